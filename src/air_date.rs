@@ -0,0 +1,228 @@
+extern crate serde;
+
+use regex::{Captures, Regex};
+
+#[derive(Debug, PartialEq)]
+pub struct AirDate {
+    pub year: i32,
+    pub month: i32,
+    pub day: i32,
+}
+
+impl serde::Serialize for AirDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&format!(
+            "{:04}-{:02}-{:02}",
+            self.year, self.month, self.day
+        ))
+    }
+}
+
+lazy_static! {
+    //Month and day are zero padded so that audio channels after a year are not read as a date:
+    //"Movie.2019.5.1.1080p"
+    pub static ref RE_YEAR_FIRST: Regex = Regex::new(
+        r"(?P<year>(?:19|20)\d{2})[\s._-](?P<month>\d{2})[\s._-](?P<day>\d{2})(?:[^\d]|$)"
+    )
+    .unwrap();
+    pub static ref RE_YEAR_LAST: Regex = Regex::new(
+        r"(?:^|[^\d])(?P<first>\d{1,2})[\s._-](?P<second>\d{1,2})[\s._-](?P<year>(?:19|20)\d{2})(?:[^\d]|$)"
+    )
+    .unwrap();
+    pub static ref RE_DAY_MONTH_NAME: Regex = Regex::new(
+        r"(?i)(?:^|[^\d])(?P<day>\d{1,2})(?:st|nd|rd|th)?[\s._-](?P<month>jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)[a-z]*[\s._,-]+(?P<year>(?:19|20)\d{2})"
+    )
+    .unwrap();
+    pub static ref RE_MONTH_NAME_DAY: Regex = Regex::new(
+        r"(?i)(?P<month>jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)[a-z]*[\s._-](?P<day>\d{1,2})(?:st|nd|rd|th)?[\s._,-]+(?P<year>(?:19|20)\d{2})"
+    )
+    .unwrap();
+}
+
+fn month_from_name(month: &str) -> i32 {
+    match month.to_lowercase().as_str() {
+        "jan" => 1,
+        "feb" => 2,
+        "mar" => 3,
+        "apr" => 4,
+        "may" => 5,
+        "jun" => 6,
+        "jul" => 7,
+        "aug" => 8,
+        "sep" => 9,
+        "oct" => 10,
+        "nov" => 11,
+        "dec" => 12,
+        _ => 0,
+    }
+}
+
+fn days_in_month(year: i32, month: i32) -> i32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn build(year: i32, month: i32, day: i32) -> Option<AirDate> {
+    if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+        return None;
+    }
+
+    Some(AirDate { year, month, day })
+}
+
+fn capture_number(captures: &Captures, group: &str) -> i32 {
+    captures
+        .name(group)
+        .map_or(0, |m| m.as_str().parse::<i32>().unwrap_or(0))
+}
+
+//Start and end offsets of the first air date in name, if any. Everything before it is usually the
+//title, everything after it the episode title and technical tokens
+pub fn position(name: &str) -> Option<(usize, usize)> {
+    [
+        RE_YEAR_FIRST.find(name),
+        RE_YEAR_LAST.find(name),
+        RE_DAY_MONTH_NAME.find(name),
+        RE_MONTH_NAME_DAY.find(name),
+    ]
+    .iter()
+    .filter_map(|m| m.map(|m| (m.start(), m.end())))
+    .min()
+}
+
+//Parse air dates used by daily shows: "2019.10.21", "21.10.2019", "21st Oct 2019", "October 21, 2019".
+//Ambiguous numeric dates with the year last are read as day first unless date_order is "mdy"
+pub fn parse(name: String, date_order: Option<&str>) -> (Option<AirDate>, String) {
+    if let Some(captures) = RE_YEAR_FIRST.captures(&name) {
+        let date = build(
+            capture_number(&captures, "year"),
+            capture_number(&captures, "month"),
+            capture_number(&captures, "day"),
+        );
+        if date.is_some() {
+            let stripped = RE_YEAR_FIRST.replace(&name, "").to_string();
+            return (date, stripped);
+        }
+    }
+
+    if let Some(captures) = RE_YEAR_LAST.captures(&name) {
+        let year = capture_number(&captures, "year");
+        let first = capture_number(&captures, "first");
+        let second = capture_number(&captures, "second");
+
+        let month_first = match date_order {
+            Some("mdy") => first <= 12,
+            _ => first <= 12 && second > 12,
+        };
+
+        let date = if month_first {
+            build(year, first, second)
+        } else {
+            build(year, second, first)
+        };
+        if date.is_some() {
+            let stripped = RE_YEAR_LAST.replace(&name, "").to_string();
+            return (date, stripped);
+        }
+    }
+
+    for reg in [&*RE_DAY_MONTH_NAME, &*RE_MONTH_NAME_DAY].iter() {
+        if let Some(captures) = reg.captures(&name) {
+            let date = build(
+                capture_number(&captures, "year"),
+                month_from_name(&captures["month"]),
+                capture_number(&captures, "day"),
+            );
+            if date.is_some() {
+                let stripped = reg.replace(&name, "").to_string();
+                return (date, stripped);
+            }
+        }
+    }
+
+    (None, name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parse_air_date() {
+        let mut test_grid: HashMap<&str, (i32, i32, i32)> = HashMap::new();
+        test_grid.insert(
+            "The.Daily.Show.2019.10.21.Guest.Name.720p.WEB",
+            (2019, 10, 21),
+        );
+        test_grid.insert("Show 2019-10-21 HDTV", (2019, 10, 21));
+        test_grid.insert("Show_2019_01_05_HDTV", (2019, 1, 5));
+        test_grid.insert("Show.2020.02.29.HDTV", (2020, 2, 29));
+        test_grid.insert("Show.21.10.2019.HDTV", (2019, 10, 21));
+        test_grid.insert("Show.05.01.2019.HDTV", (2019, 1, 5));
+        test_grid.insert("Show.10.21.2019.HDTV", (2019, 10, 21));
+        test_grid.insert("Show 21st Oct 2019 HDTV", (2019, 10, 21));
+        test_grid.insert("Show.2nd.February.2020.HDTV", (2020, 2, 2));
+        test_grid.insert("Show October 21, 2019 HDTV", (2019, 10, 21));
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let date = parse(key.to_string(), None).0.unwrap();
+
+            assert_eq!(
+                date,
+                AirDate {
+                    year: val.0,
+                    month: val.1,
+                    day: val.2
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_air_date_order() {
+        let mut test_grid: HashMap<&str, (i32, i32, i32)> = HashMap::new();
+        test_grid.insert("Show.05.01.2019.HDTV", (2019, 5, 1));
+        test_grid.insert("Show.21.10.2019.HDTV", (2019, 10, 21));
+        test_grid.insert("Show.2019.10.21.HDTV", (2019, 10, 21));
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let date = parse(key.to_string(), Some("mdy")).0.unwrap();
+
+            assert_eq!(
+                date,
+                AirDate {
+                    year: val.0,
+                    month: val.1,
+                    day: val.2
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_no_air_date() {
+        let testlist = [
+            "Movie.Title.2014.1080p.BluRay",
+            "Show.S01E01.720p.HDTV",
+            "Show.2014.13.45.HDTV",
+            "Movie.2019.5.1.1080p.BluRay",
+            "Show.2019.02.31.HDTV",
+            "Show.2019.02.29.HDTV",
+        ];
+
+        for s in testlist.iter() {
+            println!("Test item: {}", s);
+            assert_eq!(parse(s.to_string(), None).0, None);
+        }
+    }
+}
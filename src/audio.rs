@@ -1,15 +1,17 @@
 extern crate serde;
 
+use super::language;
+use super::language::Language;
 use super::utils;
 
-use regex::Regex;
+use regex::{Captures, Regex};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum AudioCodec {
     MP3,
     DolbyDigital,
     DolbyDigitalPlus,
-    DolbyAtmos,
     AAC,
     FLAC,
     DTS,
@@ -21,12 +23,72 @@ pub enum AudioCodec {
     LPCM,
 }
 
-#[derive(Debug, PartialEq)]
-pub enum AudioChannels {
-    Mono,
-    Stereo,
-    Chan51,
-    Chan71,
+//Channel layout: main channels, low frequency effects channels and height channels ("7.1.4")
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AudioChannels {
+    pub main: i32,
+    pub lfe: i32,
+    pub height: i32,
+}
+
+impl AudioChannels {
+    pub const MONO: AudioChannels = AudioChannels {
+        main: 1,
+        lfe: 0,
+        height: 0,
+    };
+    pub const STEREO: AudioChannels = AudioChannels {
+        main: 2,
+        lfe: 0,
+        height: 0,
+    };
+    pub const CHAN51: AudioChannels = AudioChannels {
+        main: 5,
+        lfe: 1,
+        height: 0,
+    };
+    pub const CHAN71: AudioChannels = AudioChannels {
+        main: 7,
+        lfe: 1,
+        height: 0,
+    };
+
+    //Layout for a bare channel count: "6ch" is 5.1, "8ch" is 7.1. Odd counts from 5 up are read
+    //as having a LFE channel on top ("5ch" is 5.1) to match common scene usage
+    fn from_count(count: i32) -> AudioChannels {
+        match count {
+            5 | 6 => AudioChannels::CHAN51,
+            7 | 8 => AudioChannels::CHAN71,
+            1..=4 => AudioChannels {
+                main: count,
+                lfe: 0,
+                height: 0,
+            },
+            _ if count % 2 == 1 => AudioChannels {
+                main: count,
+                lfe: 1,
+                height: 0,
+            },
+            _ => AudioChannels {
+                main: count - 1,
+                lfe: 1,
+                height: 0,
+            },
+        }
+    }
+}
+
+//Extension or profile on top of the base codec: DTS-HD Master Audio vs High Resolution, DTS:X,
+//DTS-ES, Dolby Digital EX and Dolby Atmos object audio carried by TrueHD or DD+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum AudioProfile {
+    MA,
+    HRA,
+    ES,
+    EX,
+    X,
+    Atmos,
 }
 
 impl serde::Serialize for AudioCodec {
@@ -42,9 +104,6 @@ impl serde::Serialize for AudioCodec {
             AudioCodec::DolbyDigitalPlus => {
                 serializer.serialize_unit_variant("AudioCodec", 0, "dolby_digital_plus")
             }
-            AudioCodec::DolbyAtmos => {
-                serializer.serialize_unit_variant("AudioCodec", 0, "dolby_atmos")
-            }
             AudioCodec::AAC => serializer.serialize_unit_variant("AudioCodec", 0, "aac"),
             AudioCodec::FLAC => serializer.serialize_unit_variant("AudioCodec", 0, "flac"),
             AudioCodec::DTS => serializer.serialize_unit_variant("AudioCodec", 0, "dts"),
@@ -66,214 +125,357 @@ impl serde::Serialize for AudioChannels {
         S: serde::Serializer,
     {
         match *self {
-            AudioChannels::Mono => serializer.serialize_unit_variant("AudioChannels", 0, "mono"),
-            AudioChannels::Stereo => {
-                serializer.serialize_unit_variant("AudioChannels", 0, "stereo")
+            AudioChannels::MONO => serializer.serialize_str("mono"),
+            AudioChannels::STEREO => serializer.serialize_str("stereo"),
+            AudioChannels {
+                main, lfe, height, ..
+            } if height > 0 => serializer.serialize_str(&format!("{}.{}.{}", main, lfe, height)),
+            AudioChannels { main, lfe, .. } => {
+                serializer.serialize_str(&format!("{}.{}", main, lfe))
             }
-            AudioChannels::Chan51 => serializer.serialize_unit_variant("AudioChannels", 0, "5.1"),
-            AudioChannels::Chan71 => serializer.serialize_unit_variant("AudioChannels", 0, "7.1"),
         }
     }
 }
 
-pub fn parse_codec(name: String) -> (Option<AudioCodec>, String) {
-    lazy_static! {
-        static ref RE_MP3: Vec<Regex> = vec![
-            Regex::new(r"(?i)MP[EG]?[-]?3").unwrap(),
-            Regex::new(r"(?i)lame3?").unwrap()
-        ];
-        static ref RE_DOLBY_DIGITAL: Vec<Regex> = vec![
-            Regex::new(r"(?i)DD").unwrap(),
-            Regex::new(r"(?i)DD5\.?1").unwrap(),
-            Regex::new(r"(?i)Dolby[ -_]?Digital").unwrap(),
-            Regex::new(r"(?i)AC3(-hq)?").unwrap(),
-            Regex::new(r"(?i)DD[-]?EX").unwrap(),
-            Regex::new(r"(?i)-EX").unwrap()
-        ];
-        static ref RE_DOLBY_DIGITAL_PLUS: Vec<Regex> = vec![
-            Regex::new(r"(?i)DD[P+]").unwrap(),
-            Regex::new(r"(?i)E[-]?AC3?").unwrap()
-        ];
-        static ref RE_DOLBY_ATMOS: Vec<Regex> =
-            vec![Regex::new(r"(?i)(Dolby)?[ ]?Atmos(phere)?").unwrap(),];
-        static ref RE_AAC: Vec<Regex> = vec![Regex::new(r"(?i)AAC").unwrap()];
-        static ref RE_FLAC: Vec<Regex> = vec![Regex::new(r"(?i)FLAC").unwrap()];
-        static ref RE_DTS: Vec<Regex> = vec![
-            Regex::new(r"(?i)DTS[-]?(ES)?").unwrap(),
-            Regex::new(r"(?i)-ES").unwrap()
-        ];
-        static ref RE_DOLBY_TRUE_HD: Vec<Regex> = vec![Regex::new(r"(?i)True[- .]?HD").unwrap()];
-        static ref RE_DTSHD: Vec<Regex> = vec![
-            Regex::new(r"(?i)DTS[- .]?(HD)[- .]?(MA)?").unwrap(),
-            Regex::new(r"(?i)HRA?").unwrap(),
-            Regex::new(r"(?i)DTSMA").unwrap()
-        ];
-        static ref RE_OPUS: Vec<Regex> = vec![Regex::new(r"(?i)OPUS").unwrap()];
-        static ref RE_VORBIS: Vec<Regex> = vec![Regex::new(r"(?i)VORBIS").unwrap()];
-        static ref RE_LPCM: Vec<Regex> = vec![Regex::new(r"(?i)LPCM").unwrap()];
-        static ref RE_PCM: Vec<Regex> = vec![Regex::new(r"(?i)PCM").unwrap()];
-    }
-
-    let mut matched_codec: Option<AudioCodec> = None;
-    let original_name = name.clone();
-
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_MP3.to_vec());
-    if matched {
-        matched_codec = Some(AudioCodec::MP3);
-
-        return (matched_codec, stripped_name);
-    }
-
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_DOLBY_DIGITAL_PLUS.to_vec());
-    if matched {
-        matched_codec = Some(AudioCodec::DolbyDigitalPlus);
-
-        return (matched_codec, stripped_name);
+impl serde::Serialize for AudioProfile {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match *self {
+            AudioProfile::MA => serializer.serialize_unit_variant("AudioProfile", 0, "ma"),
+            AudioProfile::HRA => serializer.serialize_unit_variant("AudioProfile", 0, "hra"),
+            AudioProfile::ES => serializer.serialize_unit_variant("AudioProfile", 0, "es"),
+            AudioProfile::EX => serializer.serialize_unit_variant("AudioProfile", 0, "ex"),
+            AudioProfile::X => serializer.serialize_unit_variant("AudioProfile", 0, "x"),
+            AudioProfile::Atmos => serializer.serialize_unit_variant("AudioProfile", 0, "atmos"),
+        }
     }
+}
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_DOLBY_DIGITAL.to_vec());
-    if matched {
-        matched_codec = Some(AudioCodec::DolbyDigital);
-
-        return (matched_codec, stripped_name);
-    }
+#[derive(Serialize, Debug, PartialEq)]
+pub struct AudioTrack {
+    pub codec: Option<AudioCodec>,
+    pub profile: Option<AudioProfile>,
+    pub channels: Option<AudioChannels>,
+    pub language: Option<Language>,
+}
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_DOLBY_ATMOS.to_vec());
-    if matched {
-        matched_codec = Some(AudioCodec::DolbyAtmos);
+lazy_static! {
+    static ref RE_MP3: Vec<Regex> = vec![
+        Regex::new(r"(?i)MP[EG]?[-]?3").unwrap(),
+        Regex::new(r"(?i)lame3?").unwrap()
+    ];
+    static ref RE_DOLBY_DIGITAL: Vec<Regex> = vec![
+        Regex::new(r"(?i)DD[.\s-]?EX\b").unwrap(),
+        Regex::new(r"(?i)DD").unwrap(),
+        Regex::new(r"(?i)DD5\.?1").unwrap(),
+        Regex::new(r"(?i)Dolby[ -_]?Digital").unwrap(),
+        Regex::new(r"(?i)AC3(-hq)?").unwrap(),
+        Regex::new(r"(?i)-EX\b").unwrap()
+    ];
+    static ref RE_DOLBY_DIGITAL_PLUS: Vec<Regex> = vec![
+        Regex::new(r"(?i)DD[P+]").unwrap(),
+        Regex::new(r"(?i)E[-]?AC3?").unwrap()
+    ];
+    static ref RE_AAC: Vec<Regex> = vec![Regex::new(r"(?i)AAC").unwrap()];
+    static ref RE_FLAC: Vec<Regex> = vec![Regex::new(r"(?i)FLAC").unwrap()];
+    static ref RE_DTS: Vec<Regex> = vec![
+        Regex::new(r"(?i)DTS[-]?(ES)?").unwrap(),
+        Regex::new(r"(?i)-ES\b").unwrap()
+    ];
+    static ref RE_DOLBY_TRUE_HD: Vec<Regex> = vec![Regex::new(r"(?i)True[- .]?HD").unwrap()];
+    static ref RE_DTSHD: Vec<Regex> = vec![
+        Regex::new(r"(?i)DTS[-:. ]?X\b").unwrap(),
+        Regex::new(r"(?i)DTS[- .]?(HD)[- .]?(MA)?").unwrap(),
+        Regex::new(r"(?i)HRA?").unwrap(),
+        Regex::new(r"(?i)DTSMA").unwrap()
+    ];
+    static ref RE_OPUS: Vec<Regex> = vec![Regex::new(r"(?i)OPUS").unwrap()];
+    static ref RE_VORBIS: Vec<Regex> = vec![Regex::new(r"(?i)VORBIS").unwrap()];
+    static ref RE_LPCM: Vec<Regex> = vec![Regex::new(r"(?i)LPCM").unwrap()];
+    static ref RE_PCM: Vec<Regex> = vec![Regex::new(r"(?i)PCM").unwrap()];
+    //Not preceded by a digit so that "7.1.5" is not read as "1.5"
+    static ref RE_CHANNEL_LAYOUT: Regex = Regex::new(
+        r"(?:^|[^\d])(?P<layout>(?P<main>\d{1,2})\.(?P<lfe>[01])(?:\.(?P<height>\d{1,2}))?)(?:[^\d]|$)"
+    )
+    .unwrap();
+    //Layout glued to the codec: "DD51", "TrueHD51", "AAC20". Codecs may already be masked by '#'
+    static ref RE_CHANNEL_LAYOUT_COMPACT: Regex = Regex::new(
+        r"(?i)(?:DD|HD|AAC|#)(?P<layout>(?P<main>[1-9])(?P<lfe>[01]))(?:[^\d]|$)"
+    )
+    .unwrap();
+    static ref RE_CHANNEL_COUNT: Regex =
+        Regex::new(r"(?i)(?:^|[^\d])(?P<layout>(?P<count>\d{1,2})ch)(?:[^a-z]|$)").unwrap();
+    static ref RE_MONO: Regex = Regex::new(r"(?i)mono").unwrap();
+    static ref RE_STEREO: Regex = Regex::new(r"(?i)stereo").unwrap();
+    static ref RE_PROFILE_ATMOS: Regex =
+        utils::token_regex(r"(?P<atmos>(?i:(?:Dolby[\s._-]?)?Atmos))");
+    static ref RE_PROFILE_X: Regex = Regex::new(r"(?i)DTS[-:. ]?X\b").unwrap();
+    static ref RE_PROFILE_MA: Regex = Regex::new(r"(?i)(?:HD[- .]?|DTS)MA").unwrap();
+    static ref RE_PROFILE_HRA: Regex = Regex::new(r"(?i)HRA?").unwrap();
+    static ref RE_PROFILE_ES: Regex = Regex::new(r"(?i)(?:DTS-?|-)ES\b").unwrap();
+    static ref RE_PROFILE_EX: Regex = Regex::new(r"(?i)(?:DD[.\s-]?|-)EX\b").unwrap();
+    static ref RE_TRACK_LANGUAGE: Regex =
+        Regex::new(r"(?:^|[\s._\-\[\](){}])(?P<language>[A-Za-z]{3,})[\s._-]$").unwrap();
+}
 
-        return (matched_codec, stripped_name);
-    }
+//Codec regexes in priority order: the first matching table wins when several overlap
+fn codec_table() -> Vec<(&'static Vec<Regex>, AudioCodec)> {
+    vec![
+        (&RE_MP3, AudioCodec::MP3),
+        (&RE_DOLBY_DIGITAL_PLUS, AudioCodec::DolbyDigitalPlus),
+        (&RE_DOLBY_DIGITAL, AudioCodec::DolbyDigital),
+        (&RE_AAC, AudioCodec::AAC),
+        (&RE_FLAC, AudioCodec::FLAC),
+        (&RE_DTSHD, AudioCodec::DTSHD),
+        (&RE_DTS, AudioCodec::DTS),
+        (&RE_DOLBY_TRUE_HD, AudioCodec::DolbyTrueHD),
+        (&RE_OPUS, AudioCodec::Opus),
+        (&RE_VORBIS, AudioCodec::Vorbis),
+        (&RE_LPCM, AudioCodec::LPCM),
+        (&RE_PCM, AudioCodec::PCM),
+    ]
+}
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_AAC.to_vec());
-    if matched {
-        matched_codec = Some(AudioCodec::AAC);
+fn profile_table() -> Vec<(&'static Regex, AudioProfile)> {
+    vec![
+        (&RE_PROFILE_X, AudioProfile::X),
+        (&RE_PROFILE_MA, AudioProfile::MA),
+        (&RE_PROFILE_HRA, AudioProfile::HRA),
+        (&RE_PROFILE_ES, AudioProfile::ES),
+        (&RE_PROFILE_EX, AudioProfile::EX),
+    ]
+}
 
-        return (matched_codec, stripped_name);
-    }
+//Find the profile of a codec group, e.g. "DTS-HD.MA" or "DD-EX"
+fn parse_profile(codec_name: &str) -> Option<AudioProfile> {
+    profile_table()
+        .into_iter()
+        .find(|(reg, _)| reg.is_match(codec_name))
+        .map(|(_, profile)| profile)
+}
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_FLAC.to_vec());
-    if matched {
-        matched_codec = Some(AudioCodec::FLAC);
+fn capture_number(captures: &Captures, group: &str) -> i32 {
+    captures
+        .name(group)
+        .map_or(0, |m| m.as_str().parse::<i32>().unwrap_or(0))
+}
 
-        return (matched_codec, stripped_name);
+//Find the channel layout in name: "5.1", "7.1.4", "DD51", "6ch", "10ch", "mono", "stereo"
+pub fn parse_channels(name: String) -> (Option<AudioChannels>, String) {
+    for reg in [&*RE_CHANNEL_LAYOUT, &*RE_CHANNEL_LAYOUT_COMPACT].iter() {
+        if let Some(captures) = reg.captures(&name) {
+            let layout = captures.name("layout").unwrap();
+            let channels = AudioChannels {
+                main: capture_number(&captures, "main"),
+                lfe: capture_number(&captures, "lfe"),
+                height: capture_number(&captures, "height"),
+            };
+            let stripped = format!("{}{}", &name[..layout.start()], &name[layout.end()..]);
+
+            return (Some(channels), stripped);
+        }
     }
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_DTSHD.to_vec());
-    if matched {
-        matched_codec = Some(AudioCodec::DTSHD);
+    if let Some(captures) = RE_CHANNEL_COUNT.captures(&name) {
+        let layout = captures.name("layout").unwrap();
+        let count = capture_number(&captures, "count");
+        if count > 0 {
+            let stripped = format!("{}{}", &name[..layout.start()], &name[layout.end()..]);
 
-        return (matched_codec, stripped_name);
+            return (Some(AudioChannels::from_count(count)), stripped);
+        }
     }
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_DTS.to_vec());
-    if matched {
-        matched_codec = Some(AudioCodec::DTS);
-
-        return (matched_codec, stripped_name);
+    for (reg, channels) in [
+        (&*RE_MONO, AudioChannels::MONO),
+        (&*RE_STEREO, AudioChannels::STEREO),
+    ]
+    .iter()
+    {
+        if reg.is_match(&name) {
+            return (Some(*channels), reg.replace_all(&name, "").to_string());
+        }
     }
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_DOLBY_TRUE_HD.to_vec());
-    if matched {
-        matched_codec = Some(AudioCodec::DolbyTrueHD);
+    (None, name)
+}
 
-        return (matched_codec, stripped_name);
-    }
+//Codec found in name: start and end offsets, base codec and profile
+type CodecMatch = (usize, usize, Option<AudioCodec>, Option<AudioProfile>);
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_OPUS.to_vec());
-    if matched {
-        matched_codec = Some(AudioCodec::Opus);
+//Text between two tokens of the same track: a separator, optionally around a channel layout
+//("DDP5.1.Atmos")
+fn is_same_track(gap: &str) -> bool {
+    let (channels, stripped) = parse_channels(gap.to_string());
+    stripped.chars().all(|c| " ._-".contains(c)) && (gap.len() <= 1 || channels.is_some())
+}
 
-        return (matched_codec, stripped_name);
+//Find every codec occurrence in name in order of appearance. Tables are tried in priority order
+//and matches are masked so that "DD" cannot match again inside "DDP". Matches glued to a preceding
+//letter or digit ("Addams") are ignored. Also returns name with every codec match masked
+fn find_codecs(name: &str) -> (Vec<CodecMatch>, String) {
+    let mut masked = name.to_string();
+    let mut found: Vec<(usize, usize, usize)> = Vec::new();
+
+    let atmos: Vec<(usize, usize)> = RE_PROFILE_ATMOS
+        .captures_iter(name)
+        .filter_map(|captures| captures.name("atmos"))
+        .map(|m| (m.start(), m.end()))
+        .collect();
+    for &(start, end) in atmos.iter() {
+        masked.replace_range(start..end, &"#".repeat(end - start));
     }
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_VORBIS.to_vec());
-    if matched {
-        matched_codec = Some(AudioCodec::Vorbis);
-
-        return (matched_codec, stripped_name);
+    for (priority, (regex_table, _)) in codec_table().iter().enumerate() {
+        for reg in regex_table.iter() {
+            let matches: Vec<(usize, usize)> = reg
+                .find_iter(&masked)
+                .map(|m| (m.start(), m.end()))
+                .filter(|(start, _)| {
+                    *start == 0
+                        || !masked[*start..].starts_with(char::is_alphanumeric)
+                        || !masked[..*start].ends_with(char::is_alphanumeric)
+                })
+                .collect();
+            for (start, end) in matches {
+                masked.replace_range(start..end, &"#".repeat(end - start));
+                found.push((start, end, priority));
+            }
+        }
     }
-
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_LPCM.to_vec());
-    if matched {
-        matched_codec = Some(AudioCodec::LPCM);
-
-        return (matched_codec, stripped_name);
+    found.sort();
+
+    //Adjacent codec tokens describe the same track: "DTS-HD.HRA", "DTS-HD.MA". The highest
+    //priority codec of the group is kept
+    let mut groups: Vec<(usize, usize, usize)> = Vec::new();
+    for (start, end, priority) in found {
+        if let Some(last) = groups.last_mut() {
+            let gap = &name[last.1..start];
+            if gap.len() <= 1 && is_same_track(gap) {
+                last.1 = end;
+                last.2 = last.2.min(priority);
+                continue;
+            }
+        }
+        groups.push((start, end, priority));
     }
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_PCM.to_vec());
-    if matched {
-        matched_codec = Some(AudioCodec::PCM);
-
-        return (matched_codec, stripped_name);
+    let table = codec_table();
+    let mut codecs: Vec<CodecMatch> = groups
+        .into_iter()
+        .map(|(start, end, priority)| {
+            (
+                start,
+                end,
+                Some(table[priority].1),
+                parse_profile(&name[start..end]),
+            )
+        })
+        .collect();
+
+    //Atmos is object audio carried by TrueHD or DD+, it is the profile of the track next to it:
+    //"TrueHD.Atmos", "Atmos.TrueHD", "DDP5.1.Atmos". On its own it implies TrueHD
+    for (start, end) in atmos {
+        let carrier = codecs.iter_mut().find(|codec| {
+            let is_carrier = codec.2 == Some(AudioCodec::DolbyTrueHD)
+                || codec.2 == Some(AudioCodec::DolbyDigitalPlus);
+            let before = name.get(codec.1..start).is_some_and(is_same_track);
+            let after = name.get(end..codec.0).is_some_and(is_same_track);
+            is_carrier && (before || after)
+        });
+
+        match carrier {
+            Some(codec) => {
+                codec.0 = codec.0.min(start);
+                codec.3 = Some(AudioProfile::Atmos);
+            }
+            None => codecs.push((
+                start,
+                end,
+                Some(AudioCodec::DolbyTrueHD),
+                Some(AudioProfile::Atmos),
+            )),
+        }
     }
+    codecs.sort_by_key(|codec| codec.0);
 
-    (matched_codec, original_name)
+    (codecs, masked)
 }
 
-pub fn parse_channels(name: String) -> (Option<AudioChannels>, String) {
-    lazy_static! {
-        static ref RE_MONO: Vec<Regex> = vec![
-            Regex::new(r"(?i)1ch").unwrap(),
-            Regex::new(r"(?i)mono").unwrap(),
-        ];
-        static ref RE_STEREO: Vec<Regex> = vec![
-            Regex::new(r"(?i)2ch").unwrap(),
-            Regex::new(r"(?i)2\.0").unwrap(),
-            Regex::new(r"(?i)AAC2\.?0").unwrap(),
-            Regex::new(r"(?i)stereo").unwrap(),
-        ];
-        static ref RE_CHAN51: Vec<Regex> = vec![
-            Regex::new(r"(?i)[56]ch").unwrap(),
-            Regex::new(r"(?i)5\.?1").unwrap(),
-            Regex::new(r"(?i)DD5\.?1").unwrap(),
-            Regex::new(r"(?i)True[-]?HD5\.?1").unwrap(),
-        ];
-        static ref RE_CHAN71: Vec<Regex> = vec![
-            Regex::new(r"(?i)[78]ch").unwrap(),
-            Regex::new(r"(?i)7\.1").unwrap(),
-        ];
-    }
-
-    let mut matched_channels: Option<AudioChannels> = None;
-    let original_name = name.clone();
-
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_MONO.to_vec());
-    if matched {
-        matched_channels = Some(AudioChannels::Mono);
-
-        return (matched_channels, stripped_name);
-    }
-
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_STEREO.to_vec());
-    if matched {
-        matched_channels = Some(AudioChannels::Stereo);
+//Find every audio track in name, in order of appearance. Each track reads its channels from the
+//text following it and its language from the token right before it: "GERMAN.DTS.5.1.ENGLISH.AC3.2.0"
+pub fn parse_tracks(name: String) -> (Vec<AudioTrack>, String) {
+    let (codecs, masked) = find_codecs(&name);
 
-        return (matched_channels, stripped_name);
-    }
-
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_CHAN51.to_vec());
-    if matched {
-        matched_channels = Some(AudioChannels::Chan51);
+    if codecs.is_empty() {
+        let (channels, stripped) = parse_channels(name);
+        if channels.is_none() {
+            return (Vec::new(), stripped);
+        }
 
-        return (matched_channels, stripped_name);
+        let track = AudioTrack {
+            codec: None,
+            profile: None,
+            channels,
+            language: None,
+        };
+        return (vec![track], stripped);
     }
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_CHAN71.to_vec());
-    if matched {
-        matched_channels = Some(AudioChannels::Chan71);
-
-        return (matched_channels, stripped_name);
+    let mut tracks: Vec<AudioTrack> = Vec::new();
+    let (prefix_channels, prefix_stripped) = parse_channels(name[..codecs[0].0].to_string());
+    let mut stripped = String::new();
+
+    for (index, &(start, _, codec, profile)) in codecs.iter().enumerate() {
+        let next_start = codecs.get(index + 1).map_or(name.len(), |next| next.0);
+        //Channels are read from the masked text so that those inside a group ("DDP5.1.Atmos") count
+        let (mut channels, segment_stripped) =
+            parse_channels(masked[start..next_start].to_string());
+
+        if index == 0 {
+            //Channels written before the first codec: "5.1.AC3"
+            if channels.is_none() && prefix_channels.is_some() {
+                channels = prefix_channels;
+                stripped.push_str(&prefix_stripped);
+            } else {
+                stripped.push_str(&name[..start]);
+            }
+        }
+        stripped.push_str(&segment_stripped.replace('#', ""));
+
+        let language = RE_TRACK_LANGUAGE
+            .captures(&name[..start])
+            .and_then(|x| language::from_tag(&x["language"]));
+
+        tracks.push(AudioTrack {
+            codec,
+            profile,
+            channels,
+            language,
+        });
     }
 
-    (matched_channels, original_name)
+    (tracks, stripped)
 }
 
-pub fn parse(name: String) -> (Option<AudioCodec>, Option<AudioChannels>, String) {
-    let (codec, stripped) = parse_codec(name);
-    let (channel, stripped) = parse_channels(stripped);
-
-    (codec, channel, stripped)
+//The first track is the primary one and backs the scalar codec, profile and channels properties
+pub fn parse(
+    name: String,
+) -> (
+    Vec<AudioTrack>,
+    Option<AudioCodec>,
+    Option<AudioProfile>,
+    Option<AudioChannels>,
+    String,
+) {
+    let (tracks, stripped) = parse_tracks(name);
+    let codec = tracks.first().and_then(|t| t.codec);
+    let profile = tracks.first().and_then(|t| t.profile);
+    let channels = tracks.first().and_then(|t| t.channels);
+
+    (tracks, codec, profile, channels, stripped)
 }
 
 #[cfg(test)]
@@ -305,11 +507,6 @@ mod tests {
         test_grid.insert("DD+", AudioCodec::DolbyDigitalPlus);
         test_grid.insert("EAC3", AudioCodec::DolbyDigitalPlus);
 
-        test_grid.insert("DolbyAtmos", AudioCodec::DolbyAtmos);
-        test_grid.insert("Dolby Atmos", AudioCodec::DolbyAtmos);
-        test_grid.insert("Atmos", AudioCodec::DolbyAtmos);
-        test_grid.insert("Atmosphere", AudioCodec::DolbyAtmos);
-
         test_grid.insert("AAC", AudioCodec::AAC);
         test_grid.insert("AAC-HE", AudioCodec::AAC);
         test_grid.insert("AAC-LC", AudioCodec::AAC);
@@ -350,30 +547,30 @@ mod tests {
 
         let mut channels_test_grid: HashMap<&str, AudioChannels> = HashMap::new();
 
-        channels_test_grid.insert("1ch", AudioChannels::Mono);
-        channels_test_grid.insert("mono", AudioChannels::Mono);
+        channels_test_grid.insert("1ch", AudioChannels::MONO);
+        channels_test_grid.insert("mono", AudioChannels::MONO);
 
-        channels_test_grid.insert("AAC2.0", AudioChannels::Stereo);
-        channels_test_grid.insert("AAC20", AudioChannels::Stereo);
-        channels_test_grid.insert("2ch", AudioChannels::Stereo);
-        channels_test_grid.insert("2.0", AudioChannels::Stereo);
-        channels_test_grid.insert("stereo", AudioChannels::Stereo);
+        channels_test_grid.insert("AAC2.0", AudioChannels::STEREO);
+        channels_test_grid.insert("AAC20", AudioChannels::STEREO);
+        channels_test_grid.insert("2ch", AudioChannels::STEREO);
+        channels_test_grid.insert("2.0", AudioChannels::STEREO);
+        channels_test_grid.insert("stereo", AudioChannels::STEREO);
 
-        channels_test_grid.insert("DD5.1", AudioChannels::Chan51);
-        channels_test_grid.insert("DD51", AudioChannels::Chan51);
-        channels_test_grid.insert("True-HD51", AudioChannels::Chan51);
-        channels_test_grid.insert("trueHD51", AudioChannels::Chan51);
-        channels_test_grid.insert("5.1", AudioChannels::Chan51);
-        channels_test_grid.insert("5ch", AudioChannels::Chan51);
-        channels_test_grid.insert("6ch", AudioChannels::Chan51);
+        channels_test_grid.insert("DD5.1", AudioChannels::CHAN51);
+        channels_test_grid.insert("DD51", AudioChannels::CHAN51);
+        channels_test_grid.insert("True-HD51", AudioChannels::CHAN51);
+        channels_test_grid.insert("trueHD51", AudioChannels::CHAN51);
+        channels_test_grid.insert("5.1", AudioChannels::CHAN51);
+        channels_test_grid.insert("5ch", AudioChannels::CHAN51);
+        channels_test_grid.insert("6ch", AudioChannels::CHAN51);
 
-        channels_test_grid.insert("7.1", AudioChannels::Chan71);
-        channels_test_grid.insert("7ch", AudioChannels::Chan71);
-        channels_test_grid.insert("8ch", AudioChannels::Chan71);
+        channels_test_grid.insert("7.1", AudioChannels::CHAN71);
+        channels_test_grid.insert("7ch", AudioChannels::CHAN71);
+        channels_test_grid.insert("8ch", AudioChannels::CHAN71);
 
         for (key, val) in test_grid {
             println!("Test item: {}", key);
-            let audio_codec = super::parse_codec(key.to_string()).0.unwrap();
+            let audio_codec = super::parse(key.to_string()).1.unwrap();
 
             assert_eq!(val, audio_codec);
         }
@@ -385,4 +582,192 @@ mod tests {
             assert_eq!(val, audio_channels);
         }
     }
+
+    #[test]
+    fn test_parse_channel_layouts() {
+        let mut test_grid: HashMap<&str, (i32, i32, i32, &str)> = HashMap::new();
+
+        test_grid.insert("AAC.2.1", (2, 1, 0, "2.1"));
+        test_grid.insert("FLAC.4.0", (4, 0, 0, "4.0"));
+        test_grid.insert("DTS-ES.6.1", (6, 1, 0, "6.1"));
+        test_grid.insert("TrueHD.Atmos.5.1.2", (5, 1, 2, "5.1.2"));
+        test_grid.insert("TrueHD.Atmos.7.1.4", (7, 1, 4, "7.1.4"));
+        test_grid.insert("DTS-X.7.1.5", (7, 1, 5, "7.1.5"));
+        test_grid.insert("LPCM.10ch", (9, 1, 0, "9.1"));
+        test_grid.insert("DDP5.1", (5, 1, 0, "5.1"));
+        test_grid.insert("DD51", (5, 1, 0, "5.1"));
+        test_grid.insert("AAC2.0", (2, 0, 0, "stereo"));
+        test_grid.insert("1ch", (1, 0, 0, "mono"));
+        test_grid.insert("x264.7.1.2160p", (7, 1, 0, "7.1"));
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let channels = super::parse(key.to_string()).3.unwrap();
+
+            assert_eq!(
+                channels,
+                AudioChannels {
+                    main: val.0,
+                    lfe: val.1,
+                    height: val.2
+                }
+            );
+            assert_eq!(
+                ::serde_json::to_string(&channels).unwrap(),
+                format!("\"{}\"", val.3)
+            );
+        }
+
+        assert_eq!(
+            None,
+            super::parse_channels("S01E01.2019.10.21".to_string()).0
+        );
+    }
+
+    #[test]
+    fn test_parse_audio_profile() {
+        let mut test_grid: HashMap<&str, (Option<AudioCodec>, AudioProfile)> = HashMap::new();
+
+        test_grid.insert("DTS-HD.MA", (Some(AudioCodec::DTSHD), AudioProfile::MA));
+        test_grid.insert("DTSMA", (Some(AudioCodec::DTSHD), AudioProfile::MA));
+        test_grid.insert("DTS-HD.HRA", (Some(AudioCodec::DTSHD), AudioProfile::HRA));
+        test_grid.insert("DTSHD.HR", (Some(AudioCodec::DTSHD), AudioProfile::HRA));
+        test_grid.insert("DTS-X.7.1", (Some(AudioCodec::DTSHD), AudioProfile::X));
+        test_grid.insert("DTS:X", (Some(AudioCodec::DTSHD), AudioProfile::X));
+        test_grid.insert("DTS-ES", (Some(AudioCodec::DTS), AudioProfile::ES));
+        test_grid.insert("DD-EX", (Some(AudioCodec::DolbyDigital), AudioProfile::EX));
+        test_grid.insert(
+            "TrueHD.Atmos.7.1",
+            (Some(AudioCodec::DolbyTrueHD), AudioProfile::Atmos),
+        );
+        test_grid.insert(
+            "Atmos.TrueHD",
+            (Some(AudioCodec::DolbyTrueHD), AudioProfile::Atmos),
+        );
+        test_grid.insert(
+            "DDP5.1.Atmos",
+            (Some(AudioCodec::DolbyDigitalPlus), AudioProfile::Atmos),
+        );
+        test_grid.insert(
+            "Dolby Atmos",
+            (Some(AudioCodec::DolbyTrueHD), AudioProfile::Atmos),
+        );
+        test_grid.insert(
+            "Tenet.2020.2160p.UHD.BluRay.REMUX.HDR.HEVC.Atmos-EPSiLON",
+            (Some(AudioCodec::DolbyTrueHD), AudioProfile::Atmos),
+        );
+        test_grid.insert("DD.EX.5.1", (Some(AudioCodec::DolbyDigital), AudioProfile::EX));
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let (_, codec, profile, _, _) = super::parse(key.to_string());
+
+            assert_eq!(val.0, codec);
+            assert_eq!(Some(val.1), profile);
+        }
+
+        assert_eq!(None, super::parse("TrueHD.7.1".to_string()).2);
+        assert_eq!(None, super::parse("DTS.x264".to_string()).2);
+        //Release groups starting with "ES" or "EX" are not audio profiles
+        assert_eq!(
+            Vec::<AudioTrack>::new(),
+            super::parse("Inception.2010.1080p.BluRay.x264-ESiR".to_string()).0
+        );
+        assert_eq!(
+            Vec::<AudioTrack>::new(),
+            super::parse("Movie.2010.DVDRip.x264-EXViD".to_string()).0
+        );
+        assert_eq!(
+            Vec::<AudioTrack>::new(),
+            super::parse("Atmosphere.2019.1080p.BluRay".to_string()).0
+        );
+    }
+
+    #[test]
+    fn test_parse_audio_tracks() {
+        let mut test_grid: HashMap<&str, Vec<AudioTrack>> = HashMap::new();
+
+        test_grid.insert(
+            "Movie.2019.2160p.BluRay.TrueHD.Atmos.7.1.AC3.5.1.x265",
+            vec![
+                AudioTrack {
+                    codec: Some(AudioCodec::DolbyTrueHD),
+                    profile: Some(AudioProfile::Atmos),
+                    channels: Some(AudioChannels::CHAN71),
+                    language: None,
+                },
+                AudioTrack {
+                    codec: Some(AudioCodec::DolbyDigital),
+                    profile: None,
+                    channels: Some(AudioChannels::CHAN51),
+                    language: None,
+                },
+            ],
+        );
+        test_grid.insert(
+            "Movie.2019.1080p.BluRay.DTS-HD.MA.5.1.AAC.2.0.x264",
+            vec![
+                AudioTrack {
+                    codec: Some(AudioCodec::DTSHD),
+                    profile: Some(AudioProfile::MA),
+                    channels: Some(AudioChannels::CHAN51),
+                    language: None,
+                },
+                AudioTrack {
+                    codec: Some(AudioCodec::AAC),
+                    profile: None,
+                    channels: Some(AudioChannels::STEREO),
+                    language: None,
+                },
+            ],
+        );
+        test_grid.insert(
+            "Movie.2019.1080p.GERMAN.DTS.5.1.ENGLISH.AC3.2.0.x264",
+            vec![
+                AudioTrack {
+                    codec: Some(AudioCodec::DTS),
+                    profile: None,
+                    channels: Some(AudioChannels::CHAN51),
+                    language: Some(Language::German),
+                },
+                AudioTrack {
+                    codec: Some(AudioCodec::DolbyDigital),
+                    profile: None,
+                    channels: Some(AudioChannels::STEREO),
+                    language: Some(Language::English),
+                },
+            ],
+        );
+        test_grid.insert(
+            "Show.S01E01.1080p.WEB-DL.DDP5.1.Atmos.H.264",
+            vec![AudioTrack {
+                codec: Some(AudioCodec::DolbyDigitalPlus),
+                profile: Some(AudioProfile::Atmos),
+                channels: Some(AudioChannels::CHAN51),
+                language: None,
+            }],
+        );
+        test_grid.insert(
+            "The.Addams.Family.1991.720p.5.1.x264",
+            vec![AudioTrack {
+                codec: None,
+                profile: None,
+                channels: Some(AudioChannels::CHAN51),
+                language: None,
+            }],
+        );
+        test_grid.insert("Movie.2019.1080p.BluRay.x264", vec![]);
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let tracks = super::parse_tracks(key.to_string()).0;
+
+            assert_eq!(val, tracks);
+        }
+
+        assert_eq!(
+            "Movie.2019.1080p.BluRay.....x264",
+            super::parse_tracks("Movie.2019.1080p.BluRay.DTS-HD.MA.5.1.AAC.2.0.x264".to_string()).1
+        );
+    }
 }
@@ -1,9 +1,5 @@
+#[derive(Default)]
 pub struct CliOptions<'a> {
     pub media_type: Option<&'a str>,
-}
-
-impl<'a> Default for CliOptions<'a> {
-    fn default() -> Self {
-        CliOptions { media_type: None }
-    }
+    pub date_order: Option<&'a str>,
 }
@@ -5,6 +5,7 @@ use super::utils;
 use regex::Regex;
 
 #[derive(Debug, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum Container {
     AVI,
     Matroska,
@@ -0,0 +1,91 @@
+extern crate serde;
+
+use regex::Regex;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Country {
+    Australia,
+    Canada,
+    NewZealand,
+    UnitedKingdom,
+    UnitedStates,
+}
+
+impl serde::Serialize for Country {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match *self {
+            Country::Australia => serializer.serialize_unit_variant("Country", 0, "AU"),
+            Country::Canada => serializer.serialize_unit_variant("Country", 0, "CA"),
+            Country::NewZealand => serializer.serialize_unit_variant("Country", 0, "NZ"),
+            Country::UnitedKingdom => serializer.serialize_unit_variant("Country", 0, "GB"),
+            Country::UnitedStates => serializer.serialize_unit_variant("Country", 0, "US"),
+        }
+    }
+}
+
+fn from_code(code: &str) -> Option<Country> {
+    match code {
+        "AU" => Some(Country::Australia),
+        "CA" => Some(Country::Canada),
+        "NZ" => Some(Country::NewZealand),
+        "UK" | "GB" => Some(Country::UnitedKingdom),
+        "US" => Some(Country::UnitedStates),
+        _ => None,
+    }
+}
+
+//Find a country marker at the end of a title: "The.Office.US.", "Shameless (UK)".
+//Case sensitive so that words such as "us" in "Let Us Prey" are left alone
+pub fn parse(title: &str) -> (Option<Country>, String) {
+    lazy_static! {
+        static ref RE_COUNTRY: Regex = Regex::new(
+            r"(?:^|[\s._-])(?:\((?P<marked>[A-Z]{2})\)|(?P<code>[A-Z]{2}))[\s._-]*$"
+        )
+        .unwrap();
+    }
+
+    if let Some(captures) = RE_COUNTRY.captures(title) {
+        let code = captures
+            .name("marked")
+            .or_else(|| captures.name("code"))
+            .unwrap();
+        let marker = captures.get(0).unwrap();
+        //A lone code is a title ("CA"), not a qualifier
+        if marker.start() > 0 {
+            if let Some(country) = from_code(code.as_str()) {
+                return (Some(country), title[..marker.start()].to_string());
+            }
+        }
+    }
+
+    (None, title.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parse_country() {
+        let mut test_grid: HashMap<&str, (Option<Country>, &str)> = HashMap::new();
+        test_grid.insert("The.Office.US.", (Some(Country::UnitedStates), "The.Office"));
+        test_grid.insert("Shameless (UK) ", (Some(Country::UnitedKingdom), "Shameless"));
+        test_grid.insert("Gold Rush AU", (Some(Country::Australia), "Gold Rush"));
+        test_grid.insert("House.of.Cards.", (None, "House.of.Cards."));
+        test_grid.insert("Let.Us.Prey.", (None, "Let.Us.Prey."));
+        test_grid.insert("Agents.of.SHIELD.", (None, "Agents.of.SHIELD."));
+        test_grid.insert("US", (None, "US"));
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let (country, stripped) = parse(key);
+
+            assert_eq!(val.0, country);
+            assert_eq!(val.1, stripped);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crc32fast::Hasher;
+use regex::Regex;
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+
+lazy_static! {
+    //Fansub releases end with the CRC32 of the file between brackets: "[Group] Title - 05 [A1B2C3D4].mkv"
+    static ref RE_CRC32: Regex =
+        Regex::new(r"[\[(](?P<crc32>[0-9A-Fa-f]{8})[\])]").unwrap();
+}
+
+pub fn parse(name: String) -> (Option<String>, String) {
+    let crc32 = RE_CRC32
+        .captures_iter(&name)
+        .last()
+        .map(|captures| captures["crc32"].to_uppercase());
+
+    match crc32 {
+        Some(crc32) => {
+            let stripped = RE_CRC32.replace_all(&name, "").to_string();
+            (Some(crc32), stripped)
+        }
+        None => (None, name),
+    }
+}
+
+//Compute the CRC32 checksum of a local file
+pub fn compute<P: AsRef<Path>>(path: P) -> io::Result<u32> {
+    let mut file = File::open(path)?;
+    let mut hasher = Hasher::new();
+    let mut buffer = [0; 64 * 1024];
+
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(hasher.finalize())
+}
+
+//Check the CRC32 checksum of a local file against the one found in its name.
+//Returns None when the file name carries no checksum
+pub fn verify<P: AsRef<Path>>(path: P) -> io::Result<Option<bool>> {
+    let path = path.as_ref();
+    let name = path
+        .file_name()
+        .map_or(String::new(), |name| name.to_string_lossy().to_string());
+
+    match parse(name).0 {
+        Some(expected) => Ok(Some(format!("{:08X}", compute(path)?) == expected)),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::env;
+    use std::fs;
+    use std::io::Write;
+
+    #[test]
+    fn test_parse_crc32() {
+        let mut test_grid: HashMap<&str, Option<&str>> = HashMap::new();
+        test_grid.insert(
+            "[HorribleSubs] Akame ga Kill! - 01 [720p][A1B2C3D4].mkv",
+            Some("A1B2C3D4"),
+        );
+        test_grid.insert("[Group] Title - 05 (1080p) (5e3f9a0b).mkv", Some("5E3F9A0B"));
+        test_grid.insert("[Group] Title - 05 [1080p].mkv", None);
+        test_grid.insert("Show.S01E01.720p.HDTV.x264-GRP", None);
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            assert_eq!(val.map(|v| v.to_string()), parse(key.to_string()).0);
+        }
+    }
+
+    #[test]
+    fn test_strip_crc32() {
+        assert_eq!(
+            "[Group] Title - 05 [1080p].mkv",
+            parse("[Group] Title - 05 [1080p][A1B2C3D4].mkv".to_string()).1
+        );
+    }
+
+    #[test]
+    fn test_verify_crc32() {
+        let dir = env::temp_dir().join(format!("vidocq-crc32-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        //CRC32 of "123456789" is CBF43926
+        let valid = dir.join("[Group] Title - 01 [CBF43926].mkv");
+        let corrupted = dir.join("[Group] Title - 02 [CBF43927].mkv");
+        let unmarked = dir.join("[Group] Title - 03.mkv");
+        for path in [&valid, &corrupted, &unmarked].iter() {
+            File::create(path).unwrap().write_all(b"123456789").unwrap();
+        }
+
+        assert_eq!(0xCBF4_3926, compute(&valid).unwrap());
+        assert_eq!(Some(true), verify(&valid).unwrap());
+        assert_eq!(Some(false), verify(&corrupted).unwrap());
+        assert_eq!(None, verify(&unmarked).unwrap());
+        assert!(verify(dir.join("[Group] Missing [CBF43926].mkv")).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
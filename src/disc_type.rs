@@ -0,0 +1,107 @@
+extern crate serde;
+
+use super::title;
+use super::utils;
+
+use regex::Regex;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum DiscType {
+    BD25,
+    BD50,
+    BD66,
+    BD100,
+    DVD5,
+    DVD9,
+    FullBluRay,
+    FullDVD,
+    ISO,
+}
+
+impl serde::Serialize for DiscType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match *self {
+            DiscType::BD25 => serializer.serialize_unit_variant("DiscType", 0, "bd25"),
+            DiscType::BD50 => serializer.serialize_unit_variant("DiscType", 0, "bd50"),
+            DiscType::BD66 => serializer.serialize_unit_variant("DiscType", 0, "uhd_bd66"),
+            DiscType::BD100 => serializer.serialize_unit_variant("DiscType", 0, "uhd_bd100"),
+            DiscType::DVD5 => serializer.serialize_unit_variant("DiscType", 0, "dvd5"),
+            DiscType::DVD9 => serializer.serialize_unit_variant("DiscType", 0, "dvd9"),
+            DiscType::FullBluRay => serializer.serialize_unit_variant("DiscType", 0, "full_bluray"),
+            DiscType::FullDVD => serializer.serialize_unit_variant("DiscType", 0, "full_dvd"),
+            DiscType::ISO => serializer.serialize_unit_variant("DiscType", 0, "iso"),
+        }
+    }
+}
+
+lazy_static! {
+    //Sized disc images first, then full discs of unknown size
+    static ref DISC_TYPE_TABLE: Vec<(Regex, DiscType)> = vec![
+        (utils::token_regex(r"(?i:(?:UHD[\s._-]?)?BD[\s._-]?66)"), DiscType::BD66),
+        (utils::token_regex(r"(?i:(?:UHD[\s._-]?)?BD[\s._-]?100)"), DiscType::BD100),
+        (utils::token_regex(r"(?i:BD[\s._-]?25)"), DiscType::BD25),
+        (utils::token_regex(r"(?i:BD[\s._-]?50)"), DiscType::BD50),
+        (utils::token_regex(r"(?i:DVD[\s._-]?5)"), DiscType::DVD5),
+        (utils::token_regex(r"(?i:DVD[\s._-]?9)"), DiscType::DVD9),
+        (
+            utils::token_regex(r"(?i:COMPLETE[\s._-]?(?:UHD[\s._-]?)?Blu-?Ray|BDMV)"),
+            DiscType::FullBluRay,
+        ),
+        (
+            utils::token_regex(r"(?i:COMPLETE[\s._-]?DVD|DVD[\s._-]?Full|VIDEO_TS)"),
+            DiscType::FullDVD,
+        ),
+        (utils::token_regex(r"(?i:ISO)"), DiscType::ISO),
+    ];
+}
+
+//Find full disc releases: disc images of a known size ("BD25", "UHD.BD66", "DVD9") or complete
+//discs ("COMPLETE.BLURAY", "BDMV", "ISO"). Tokens are left in place for release_type::parse.
+//Only the part after the title boundary is searched: "The.ISO.Project.2019" is not a disc image
+pub fn parse(name: &str) -> Option<DiscType> {
+    let boundary = title::title_boundary(name).unwrap_or(0);
+
+    DISC_TYPE_TABLE
+        .iter()
+        .find(|(reg, _)| reg.is_match(&name[boundary..]))
+        .map(|(_, disc_type)| *disc_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parse_disc_type() {
+        let mut test_grid: HashMap<&str, Option<DiscType>> = HashMap::new();
+        test_grid.insert("Movie.2019.1080p.BD25.AVC.DTS-HD.MA.5.1", Some(DiscType::BD25));
+        test_grid.insert("Movie.2019.1080p.BD50.AVC.TrueHD", Some(DiscType::BD50));
+        test_grid.insert("Movie.2019.2160p.UHD.BD66.HEVC", Some(DiscType::BD66));
+        test_grid.insert("Movie.2019.2160p.UHD-BD100.HEVC", Some(DiscType::BD100));
+        test_grid.insert("Movie.2004.PAL.DVD5-GRP", Some(DiscType::DVD5));
+        test_grid.insert("Movie.2004.NTSC.DVD9-GRP", Some(DiscType::DVD9));
+        test_grid.insert(
+            "Movie.2019.COMPLETE.BLURAY-GRP",
+            Some(DiscType::FullBluRay),
+        );
+        test_grid.insert(
+            "Movie.2019.2160p.COMPLETE.UHD.BLURAY-GRP",
+            Some(DiscType::FullBluRay),
+        );
+        test_grid.insert("Movie.2004.DVD-Full-GRP", Some(DiscType::FullDVD));
+        test_grid.insert("Movie.2019.1080p.BluRay.ISO-GRP", Some(DiscType::ISO));
+        test_grid.insert("Movie.2019.1080p.BluRay.REMUX.AVC-GRP", None);
+        test_grid.insert("Movie.2019.1080p.BluRay.x264-GRP", None);
+        test_grid.insert("The.ISO.Project.2019.720p", None);
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            assert_eq!(val, parse(key));
+        }
+    }
+}
@@ -0,0 +1,210 @@
+extern crate serde;
+
+use super::utils;
+use super::year;
+
+use regex::Regex;
+
+#[derive(Debug, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Edition {
+    Extended,
+    DirectorsCut,
+    Unrated,
+    Uncut,
+    Theatrical,
+    Remastered,
+    Criterion,
+    IMAX,
+    OpenMatte,
+    Special,
+    Anniversary,
+    Other(String),
+}
+
+impl serde::Serialize for Edition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match *self {
+            Edition::Extended => serializer.serialize_unit_variant("Edition", 0, "extended"),
+            Edition::DirectorsCut => {
+                serializer.serialize_unit_variant("Edition", 0, "directors_cut")
+            }
+            Edition::Unrated => serializer.serialize_unit_variant("Edition", 0, "unrated"),
+            Edition::Uncut => serializer.serialize_unit_variant("Edition", 0, "uncut"),
+            Edition::Theatrical => serializer.serialize_unit_variant("Edition", 0, "theatrical"),
+            Edition::Remastered => serializer.serialize_unit_variant("Edition", 0, "remastered"),
+            Edition::Criterion => serializer.serialize_unit_variant("Edition", 0, "criterion"),
+            Edition::IMAX => serializer.serialize_unit_variant("Edition", 0, "imax"),
+            Edition::OpenMatte => serializer.serialize_unit_variant("Edition", 0, "open_matte"),
+            Edition::Special => serializer.serialize_unit_variant("Edition", 0, "special_edition"),
+            Edition::Anniversary => {
+                serializer.serialize_unit_variant("Edition", 0, "anniversary_edition")
+            }
+            Edition::Other(ref edition) => serializer.serialize_str(edition),
+        }
+    }
+}
+
+fn parse_edition(name: String) -> (Option<Edition>, String) {
+    lazy_static! {
+        static ref RE_EXTENDED: Vec<Regex> = vec![utils::token_regex(
+            r"(?i:extended(?:[\s._-]?(?:cut|edition|version))?)"
+        )];
+        static ref RE_DIRECTORS_CUT: Vec<Regex> = vec![
+            utils::token_regex(r"(?i:director'?s?[\s._-]?cut(?:[\s._-]?edition)?)"),
+            utils::token_regex(r"DC"),
+        ];
+        static ref RE_UNRATED: Vec<Regex> = vec![utils::token_regex(r"(?i:unrated)")];
+        static ref RE_UNCUT: Vec<Regex> = vec![utils::token_regex(r"(?i:uncut)")];
+        static ref RE_THEATRICAL: Vec<Regex> = vec![utils::token_regex(
+            r"(?i:theatrical(?:[\s._-]?(?:cut|edition|version))?)"
+        )];
+        static ref RE_REMASTERED: Vec<Regex> =
+            vec![utils::token_regex(r"(?i:(?:4k[\s._-]?)?remaster(?:ed)?)")];
+        static ref RE_CRITERION: Vec<Regex> = vec![utils::token_regex(
+            r"(?i:criterion(?:[\s._-]?(?:collection|edition))?)"
+        )];
+        static ref RE_IMAX: Vec<Regex> = vec![utils::token_regex(r"(?i:imax(?:[\s._-]?edition)?)")];
+        static ref RE_OPEN_MATTE: Vec<Regex> = vec![utils::token_regex(r"(?i:open[\s._-]?matte)")];
+        static ref RE_SPECIAL_EDITION: Vec<Regex> =
+            vec![utils::token_regex(r"(?i:special[\s._-]?edition)")];
+        static ref RE_ANNIVERSARY_EDITION: Vec<Regex> = vec![utils::token_regex(
+            r"(?i:(?:\d{1,3}(?:th|st|nd|rd)?[\s._-]?)?anniversary(?:[\s._-]?edition)?)"
+        )];
+    }
+
+    let editions: Vec<(&Vec<Regex>, Edition)> = vec![
+        (&RE_EXTENDED, Edition::Extended),
+        (&RE_DIRECTORS_CUT, Edition::DirectorsCut),
+        (&RE_UNRATED, Edition::Unrated),
+        (&RE_UNCUT, Edition::Uncut),
+        (&RE_THEATRICAL, Edition::Theatrical),
+        (&RE_REMASTERED, Edition::Remastered),
+        (&RE_CRITERION, Edition::Criterion),
+        (&RE_IMAX, Edition::IMAX),
+        (&RE_OPEN_MATTE, Edition::OpenMatte),
+        (&RE_SPECIAL_EDITION, Edition::Special),
+        (&RE_ANNIVERSARY_EDITION, Edition::Anniversary),
+    ];
+
+    for (regex_table, edition) in editions {
+        let (matched, stripped_name) = utils::find_and_strip_token(&name, regex_table.to_vec());
+        if matched {
+            return (Some(edition), stripped_name);
+        }
+    }
+
+    (None, name)
+}
+
+lazy_static! {
+    static ref RE_PLEX_EDITION: Regex = Regex::new(r"\{edition-(?P<edition>[^}]+)\}").unwrap();
+}
+
+//Remove Plex edition tags so that other detectors do not read their content: the "-Ex" of
+//"{edition-Extended}" is not a Dolby Digital EX track
+pub fn strip_plex(name: &str) -> String {
+    RE_PLEX_EDITION.replace_all(name, "").to_string()
+}
+
+pub fn parse(name: String) -> (Option<Edition>, String) {
+    //Plex naming: "Movie (2014) {edition-Director's Cut}". Unknown editions are kept as is
+    if let Some(captures) = RE_PLEX_EDITION.captures(&name) {
+        let edition_str = captures["edition"].trim().to_string();
+        let stripped = RE_PLEX_EDITION.replace_all(&name, "").to_string();
+        let edition = match parse_edition(edition_str.clone()).0 {
+            Some(edition) => edition,
+            None => Edition::Other(edition_str),
+        };
+
+        return (Some(edition), stripped);
+    }
+
+    //Edition markers are only looked for after the year so that titles like "Uncut Gems" are kept
+    let year_str: String = year::parse(&name).to_string();
+    let year_offset: usize = if year_str != "0" {
+        name.rfind(&year_str).unwrap_or(0)
+    } else {
+        0
+    };
+
+    let (edition, stripped) = parse_edition(name[year_offset..].to_string());
+
+    (edition, format!("{}{}", &name[..year_offset], stripped))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parse_edition() {
+        let mut test_grid: HashMap<&str, Edition> = HashMap::new();
+        test_grid.insert("Hercules.2014.EXTENDED.1080p.WEB-DL", Edition::Extended);
+        test_grid.insert("Movie.2001.Extended.Cut.720p", Edition::Extended);
+        test_grid.insert("Movie.2001.Directors.Cut.720p", Edition::DirectorsCut);
+        test_grid.insert("Movie 2001 Director's Cut 720p", Edition::DirectorsCut);
+        test_grid.insert("Movie.2001.DC.720p", Edition::DirectorsCut);
+        test_grid.insert("Movie.2001.UNRATED.720p", Edition::Unrated);
+        test_grid.insert("Movie.2001.UNCUT.720p", Edition::Uncut);
+        test_grid.insert("Movie.2001.Theatrical.Cut.720p", Edition::Theatrical);
+        test_grid.insert("Movie.2001.REMASTERED.720p", Edition::Remastered);
+        test_grid.insert("Movie.2001.4K.Remaster.2160p", Edition::Remastered);
+        test_grid.insert("Movie.2001.Criterion.Collection.1080p", Edition::Criterion);
+        test_grid.insert("Movie.2001.IMAX.1080p", Edition::IMAX);
+        test_grid.insert("Movie.2001.Open.Matte.1080p", Edition::OpenMatte);
+        test_grid.insert("Movie.2001.Special.Edition.1080p", Edition::Special);
+        test_grid.insert(
+            "Movie.2001.25th.Anniversary.Edition.1080p",
+            Edition::Anniversary,
+        );
+        test_grid.insert(
+            "Movie (2001) {edition-Director's Cut}.mkv",
+            Edition::DirectorsCut,
+        );
+        test_grid.insert(
+            "Movie (2001) {edition-Ultimate Fan Cut}.mkv",
+            Edition::Other("Ultimate Fan Cut".to_string()),
+        );
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let edition = parse(key.to_string()).0.unwrap();
+
+            assert_eq!(val, edition);
+        }
+    }
+
+    #[test]
+    fn test_parse_no_edition() {
+        let testlist = [
+            "Uncut.Gems.2019.1080p.WEB-DL",
+            "The.Extended.Family.2019.720p",
+            "Movie.2001.1080p.BluRay",
+        ];
+
+        for s in testlist.iter() {
+            println!("Test item: {}", s);
+            assert_eq!(parse(s.to_string()).0, None);
+        }
+    }
+
+    #[test]
+    fn test_strip_edition() {
+        let mut test_grid: HashMap<&str, &str> = HashMap::new();
+        test_grid.insert("Movie.2001.Extended.Cut.720p", "Movie.2001.720p");
+        test_grid.insert(
+            "Movie (2001) {edition-Director's Cut}.mkv",
+            "Movie (2001) .mkv",
+        );
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            assert_eq!(val, parse(key.to_string()).1);
+        }
+    }
+}
@@ -1,3 +1,9 @@
+extern crate serde;
+
+use super::air_date;
+use super::utils;
+use super::year;
+
 use regex::Regex;
 
 lazy_static! {
@@ -7,17 +13,309 @@ lazy_static! {
         Regex::new(r"(?i)(s)?(?P<season>\d{1,3})\s?[-:]\s?(?P<episode>\d{1,3})").unwrap();
     pub static ref RE_SEASON: Regex =
         Regex::new(r"(?i)s(eason)?(\s*)?(?P<season>\d{1,3})").unwrap();
+    //Season marker standing on its own: "S01", "Season 2". Unlike RE_SEASON, "Series 720p" and
+    //"Avengers 2012" do not match
+    pub static ref RE_SEASON_MARKER: Regex = Regex::new(
+        r"(?i)(?:^|[\s._\-\[(/])(?:s|season[\s._-]?)(?P<season>\d{1,2})(?:\D|$)"
+    )
+    .unwrap();
     pub static ref RE_EPISODE: Regex =
         Regex::new(r"(?i)e(pisode)?(\s*)?(?P<episode>\d{1,3})").unwrap();
+    pub static ref RE_SEASON_RANGE: Regex = Regex::new(
+        r"(?i)seasons?[\s._-]?(?P<start>\d{1,3})[\s._]?(?:-|to|&)[\s._]?(?:seasons?[\s._-]?)?(?P<end>\d{1,3})|s(?P<start_short>\d{1,3})[\s._]?-[\s._]?s(?P<end_short>\d{1,3})"
+    )
+    .unwrap();
+    pub static ref RE_ABSOLUTE_EPISODE: Regex =
+        Regex::new(r"\s-\s(?P<episode>\d{1,4})(?:v\d)?(?:[\s\[(._]|$)").unwrap();
+    pub static ref RE_COMPLETE_SERIES: Regex =
+        Regex::new(r"(?i)complete[\s._-]?series").unwrap();
+    static ref RE_EPISODE_CONTINUATION: Regex = Regex::new(
+        r"(?i)^(?P<separator>\s?-\s?)?(?P<marker>(?:s\d{1,3})?ep?|\d{1,3}x)?(?P<episode>\d{1,3})"
+    )
+    .unwrap();
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum SpecialKind {
+    Special,
+    OVA,
+    ONA,
+    OAD,
+    Pilot,
+    NCOP,
+    NCED,
+}
+
+impl serde::Serialize for SpecialKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match *self {
+            SpecialKind::Special => serializer.serialize_unit_variant("SpecialKind", 0, "special"),
+            SpecialKind::OVA => serializer.serialize_unit_variant("SpecialKind", 0, "ova"),
+            SpecialKind::ONA => serializer.serialize_unit_variant("SpecialKind", 0, "ona"),
+            SpecialKind::OAD => serializer.serialize_unit_variant("SpecialKind", 0, "oad"),
+            SpecialKind::Pilot => serializer.serialize_unit_variant("SpecialKind", 0, "pilot"),
+            SpecialKind::NCOP => serializer.serialize_unit_variant("SpecialKind", 0, "ncop"),
+            SpecialKind::NCED => serializer.serialize_unit_variant("SpecialKind", 0, "nced"),
+        }
+    }
+}
+
+lazy_static! {
+    static ref RE_SEASON_ZERO: Regex = Regex::new(
+        r"(?i)(?:^|[\s._\-\[(])s(?:eason[\s._-]?)?0{1,3}(?:e(?P<episode>\d{1,3}))?(?:[\s._\-\])]|$)"
+    )
+    .unwrap();
+    //Anime extras are specials whatever the numbering around them: "Title S01 OVA", "Title - NCOP1"
+    static ref SPECIAL_TABLE: Vec<(Regex, SpecialKind)> = vec![
+        (utils::token_regex(r"(?i:SP(?P<episode>\d{1,3}))"), SpecialKind::Special),
+        (
+            utils::token_regex(r"(?i:OVA|OAV)(?:[\s._-]*(?P<episode>\d{1,3}))?"),
+            SpecialKind::OVA,
+        ),
+        (
+            utils::token_regex(r"(?i:ONA)(?:[\s._-]*(?P<episode>\d{1,3}))?"),
+            SpecialKind::ONA,
+        ),
+        (
+            utils::token_regex(r"(?i:OAD)(?:[\s._-]*(?P<episode>\d{1,3}))?"),
+            SpecialKind::OAD,
+        ),
+        (
+            utils::token_regex(r"(?i:NCOP)(?:[\s._-]?(?P<episode>\d{1,2}))?"),
+            SpecialKind::NCOP,
+        ),
+        (
+            utils::token_regex(r"(?i:NCED)(?:[\s._-]?(?P<episode>\d{1,2}))?"),
+            SpecialKind::NCED,
+        ),
+    ];
+    //Words that only mark a special when the release has no regular season number: "Show.S01E01.Pilot"
+    //is the first episode titled "Pilot"
+    static ref SPECIAL_WORD_TABLE: Vec<(Regex, SpecialKind)> = vec![
+        (utils::token_regex(r"(?i:pilot)"), SpecialKind::Pilot),
+        (utils::token_regex(r"(?i:specials?)"), SpecialKind::Special),
+    ];
+    static ref RE_SPECIAL_EDITION: Regex = Regex::new(r"(?i)special[\s._-]?edition").unwrap();
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct EpisodeRange {
+    pub start: i32,
+    pub end: i32,
+}
+
+//Build a range from an episode list if it covers contiguous episodes
+pub fn range(episodes: &[i32]) -> Option<EpisodeRange> {
+    if episodes.len() < 2 {
+        return None;
+    }
+
+    let contiguous = episodes.windows(2).all(|w| w[1] == w[0] + 1);
+    if !contiguous {
+        return None;
+    }
+
+    Some(EpisodeRange {
+        start: episodes[0],
+        end: episodes[episodes.len() - 1],
+    })
+}
+
+//Find additional episodes following a season/episode marker: "S01E01E02", "S01E01-E03", "S01E01-03", "1x01-1x02"
+//Also returns the end offset of the whole marker
+fn parse_multi_episode(name: &str) -> (i32, Vec<i32>, usize) {
+    let captures = match RE_SEASON_AND_EPISODE.captures(name) {
+        Some(c) => c,
+        None => return (0, Vec::new(), 0),
+    };
+
+    let season = captures["season"].parse::<i32>().unwrap_or(0);
+    let mut episodes: Vec<i32> = vec![captures["episode"].parse::<i32>().unwrap_or(0)];
+    let mut rest = &name[captures.get(0).map_or(0, |m| m.end())..];
+
+    while let Some(next) = RE_EPISODE_CONTINUATION.captures(rest) {
+        let end = next.get(0).map_or(0, |m| m.end());
+        let separator = next.name("separator").map(|m| m.as_str());
+        let is_range = separator.is_some();
+
+        if next.name("marker").is_none() {
+            //Bare digits glued to the previous episode number are not a new episode
+            let is_range_end = match separator {
+                None => false,
+                //"S01E01-03.720p"
+                Some("-") => rest[end..]
+                    .chars()
+                    .next()
+                    .is_none_or(|c| !c.is_alphanumeric()),
+                //A spaced dash usually introduces the episode title: "S01E05 - 10 Things I Hate"
+                Some(_) => rest[end..].chars().all(|c| !c.is_alphanumeric()),
+            };
+            if !is_range_end {
+                break;
+            }
+        }
+
+        //Digits followed by more digits or a resolution marker ("-720p") are not episode numbers
+        if let Some(c) = rest[end..].chars().next() {
+            if c.is_ascii_digit() || c == 'p' || c == 'P' || c == 'i' || c == 'I' {
+                break;
+            }
+        }
+
+        let episode = next["episode"].parse::<i32>().unwrap_or(0);
+        let previous = episodes[episodes.len() - 1];
+
+        if is_range && episode > previous {
+            episodes.extend(previous + 1..=episode);
+        } else {
+            episodes.push(episode);
+        }
+
+        rest = &rest[end..];
+    }
+
+    (season, episodes, name.len() - rest.len())
+}
+
+//End offset of the season/episode marker including its additional episodes: "S01E01-03", "1x01-1x02"
+pub fn multi_episode_end(name: &str) -> Option<usize> {
+    match parse_multi_episode(name) {
+        (_, ref episodes, end) if !episodes.is_empty() => Some(end),
+        _ => None,
+    }
+}
+
+//Find season ranges used by multi-season packs: "Season 1-3", "Seasons 1 to 3", "S01-S05"
+fn parse_season_range(name: &str) -> Vec<i32> {
+    RE_SEASON_RANGE.captures(name).map_or(Vec::new(), |x| {
+        let start = x
+            .name("start")
+            .or_else(|| x.name("start_short"))
+            .map_or(0, |m| m.as_str().parse::<i32>().unwrap_or(0));
+        let end = x
+            .name("end")
+            .or_else(|| x.name("end_short"))
+            .map_or(0, |m| m.as_str().parse::<i32>().unwrap_or(0));
+
+        if start == 0 || end < start {
+            return Vec::new();
+        }
+
+        (start..=end).collect()
+    })
+}
+
+//A name covering one or more full seasons instead of specific episodes is a pack
+pub fn parse_pack(name: &str, seasons: &[i32], episodes: &[i32]) -> bool {
+    if !episodes.is_empty() {
+        return false;
+    }
+    //Specials with no episode number are single episodes ("Christmas Special") unless all of season 0 is named
+    if seasons == [0] {
+        return RE_SEASON_ZERO.is_match(name);
+    }
+
+    let has_season_marker = RE_SEASON_MARKER.is_match(name) || RE_SEASON_RANGE.is_match(name);
+
+    (!seasons.is_empty() && has_season_marker) || RE_COMPLETE_SERIES.is_match(name)
+}
+
+//Find absolute episode numbers used by anime releases with no season marker: "[Group] Title - 1071 [1080p]"
+pub fn parse_absolute(name: &str) -> i32 {
+    if RE_SEASON_AND_EPISODE.is_match(name) || RE_SEASON.is_match(name) {
+        return 0;
+    }
+
+    RE_ABSOLUTE_EPISODE
+        .captures(name)
+        .map_or(0, |x| x["episode"].parse::<i32>().unwrap_or(0))
 }
 
-pub fn parse(name: String) -> (i32, i32, String) {
-    let (season, episode): (i32, i32) = RE_SEASON_AND_EPISODE.captures(&name).map_or((0, 0), |x| {
-        let season = x["season"].to_string().parse::<i32>().unwrap_or(0);
-        let episode = x["episode"].to_string().parse::<i32>().unwrap_or(0);
+fn capture_episode(captures: &regex::Captures) -> i32 {
+    captures
+        .name("episode")
+        .map_or(0, |m| m.as_str().parse::<i32>().unwrap_or(0))
+}
 
-        (season, episode)
+//Find a special episode marker. Returns its kind, special episode number (0 if none) and offsets
+fn find_special(name: &str) -> Option<(SpecialKind, i32, usize, usize)> {
+    if let Some(captures) = RE_SEASON_ZERO.captures(name) {
+        let m = captures.get(0).unwrap();
+        return Some((SpecialKind::Special, capture_episode(&captures), m.start(), m.end()));
+    }
+
+    for (reg, kind) in SPECIAL_TABLE.iter() {
+        if let Some(captures) = reg.captures(name) {
+            let m = captures.get(0).unwrap();
+            return Some((*kind, capture_episode(&captures), m.start(), m.end()));
+        }
+    }
+
+    //Words after a season, absolute episode number or air date belong to the episode title:
+    //"[Group] Anime - 05 - Special Delivery", "Show.2019.10.21.Special.Guest"
+    let (seasons, episodes, _) = parse(name.to_string());
+    if !seasons.is_empty() || parse_absolute(name) != 0 || air_date::position(name).is_some() {
+        return None;
+    }
+    let marker_start = if episodes.is_empty() {
+        name.len()
+    } else {
+        RE_EPISODE.find(name).map_or(name.len(), |m| m.start())
+    };
+
+    //"Special Edition" is a movie edition
+    let name = RE_SPECIAL_EDITION.replace_all(name, |caps: &regex::Captures| {
+        "#".repeat(caps[0].len())
     });
+    //A leading word or a word followed by the year is part of the title: "Special.Forces.2011",
+    //"The.Pilot.2015.1080p". "Doctor.Who.2005.Christmas.Special" comes after the year
+    let year_str: String = year::parse(&name).to_string();
+    let title_end = match year_str.as_str() {
+        "0" => 0,
+        _ => name.rfind(&year_str).unwrap_or(0),
+    };
+    for (reg, kind) in SPECIAL_WORD_TABLE.iter() {
+        if let Some(m) = reg
+            .find(&name)
+            .filter(|m| m.start() > 0 && m.start() >= title_end && m.end() <= marker_start)
+        {
+            return Some((*kind, 0, m.start(), m.end()));
+        }
+    }
+
+    None
+}
+
+//Find special episodes: "S00E05", "Season 00", "SP01", "Christmas Special", "Pilot", anime OVAs, ONAs,
+//OADs and creditless openings and endings. Specials belong to season 0
+pub fn parse_special(name: &str) -> (Option<SpecialKind>, i32) {
+    match find_special(name) {
+        Some((kind, episode, _, _)) => (Some(kind), episode),
+        None => (None, 0),
+    }
+}
+
+//Start and end offsets of the special episode marker in name
+pub fn special_position(name: &str) -> Option<(usize, usize)> {
+    find_special(name).map(|(_, _, start, end)| (start, end))
+}
+
+pub fn parse(name: String) -> (Vec<i32>, Vec<i32>, String) {
+    //Season and episode marker takes precedence: "S01E01-03" must not be read as season 1 episode 3
+    let (season, episodes, _) = parse_multi_episode(&name);
+    if season != 0 && !episodes.is_empty() && !episodes.contains(&0) {
+        return (vec![season], episodes, name);
+    }
+
+    let seasons = parse_season_range(&name);
+    if !seasons.is_empty() {
+        return (seasons, Vec::new(), name);
+    }
+    let episode = episodes.first().cloned().unwrap_or(0);
 
     let (season_sep, episode_sep): (i32, i32) = RE_SEASON_AND_EPISODE_SEPARATED
         .captures(&name)
@@ -28,7 +326,7 @@ pub fn parse(name: String) -> (i32, i32, String) {
             (season, episode)
         });
 
-    let season_only: i32 = RE_SEASON
+    let season_only: i32 = RE_SEASON_MARKER
         .captures(&name)
         .map_or(0, |x| x["season"].to_string().parse::<i32>().unwrap_or(0));
 
@@ -36,19 +334,32 @@ pub fn parse(name: String) -> (i32, i32, String) {
         .captures(&name)
         .map_or(0, |x| x["episode"].to_string().parse::<i32>().unwrap_or(0));
 
-    if season_sep != 0 && episode_sep != 0 {
-        return (season_sep, episode_sep, name);
+    //"Title 100 - 05" is an absolute episode number, not season 100 episode 5
+    if season_sep != 0 && episode_sep != 0 && parse_absolute(&name) == 0 {
+        return (vec![season_sep], vec![episode_sep], name);
     }
 
     let season_return = if season == 0 { season_only } else { season };
     let episode_return = if episode == 0 { episode_only } else { episode };
 
-    (season_return, episode_return, name)
+    let seasons = if season_return == 0 {
+        Vec::new()
+    } else {
+        vec![season_return]
+    };
+    let episodes = if episode_return == 0 {
+        Vec::new()
+    } else {
+        vec![episode_return]
+    };
+
+    (seasons, episodes, name)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
 
     #[test]
     fn test_parse_episode() {
@@ -76,8 +387,117 @@ mod tests {
         for s in testlist.iter() {
             println!("Test item: {}", s);
             let info = parse(s.to_string());
-            assert_eq!(info.0, 2);
-            assert_eq!(info.1, 5);
+            assert_eq!(info.0, vec![2]);
+            assert_eq!(info.1, vec![5]);
         }
     }
+
+    #[test]
+    fn test_parse_multi_episode() {
+        let mut test_grid: HashMap<&str, Vec<i32>> = HashMap::new();
+        test_grid.insert("Show.S01E01E02.720p", vec![1, 2]);
+        test_grid.insert("Show.S01E01E02E03.720p", vec![1, 2, 3]);
+        test_grid.insert("Show.S01E01E03.720p", vec![1, 3]);
+        test_grid.insert("Show.S01E01-E03.720p", vec![1, 2, 3]);
+        test_grid.insert("Show.S01E01-03.720p", vec![1, 2, 3]);
+        test_grid.insert("Show 1x01-1x02 HDTV", vec![1, 2]);
+        test_grid.insert("Show S01E01 - E02 HDTV", vec![1, 2]);
+        test_grid.insert("Show.S01E01-720p", vec![1]);
+        test_grid.insert("Show.S01E01.2015", vec![1]);
+        test_grid.insert("Show - S01E05 - 10 Things I Hate.mkv", vec![5]);
+        test_grid.insert("Show - S01E05 - 06", vec![5, 6]);
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let info = parse(key.to_string());
+            assert_eq!(info.0, vec![1]);
+            assert_eq!(info.1, val);
+        }
+    }
+
+    #[test]
+    fn test_parse_season_pack() {
+        let mut test_grid: HashMap<&str, (Vec<i32>, bool)> = HashMap::new();
+        test_grid.insert("Show.S01.COMPLETE.1080p", (vec![1], true));
+        test_grid.insert("Show Season 2 720p", (vec![2], true));
+        test_grid.insert("Show Season 1-3", (vec![1, 2, 3], true));
+        test_grid.insert("Show Seasons 1 to 3", (vec![1, 2, 3], true));
+        test_grid.insert("Show.S01-S05.BluRay", (vec![1, 2, 3, 4, 5], true));
+        test_grid.insert("Show.Complete.Series.1080p", (vec![], true));
+        test_grid.insert("Show.S01E01.720p", (vec![1], false));
+        test_grid.insert("[Group] Show S2 - 03 [1080p]", (vec![2], false));
+        test_grid.insert("Show Complete Series 720p", (vec![], true));
+        test_grid.insert("Avengers 2012 1080p BluRay", (vec![], false));
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let info = parse(key.to_string());
+            assert_eq!(info.0, val.0);
+            assert_eq!(parse_pack(key, &info.0, &info.1), val.1);
+        }
+    }
+
+    #[test]
+    fn test_parse_absolute_episode() {
+        let mut test_grid: HashMap<&str, i32> = HashMap::new();
+        test_grid.insert("[SubsPlease] One Piece - 1071 [1080p].mkv", 1071);
+        test_grid.insert("Naruto Shippuuden - 345v2", 345);
+        test_grid.insert("[Group] Mob Psycho 100 - 05 (720p).mkv", 5);
+        test_grid.insert("[Group] Title - 12.mkv", 12);
+        test_grid.insert("[HorribleSubs] One Punch Man S2 - 03 [1080p].mkv", 0);
+        test_grid.insert("Show.S01E05 - 720p", 0);
+        test_grid.insert("2047 - Sights of Death (2014)", 0);
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            assert_eq!(parse_absolute(key), val);
+        }
+
+        let info = parse("[Group] Mob Psycho 100 - 05 (720p).mkv".to_string());
+        assert_eq!(info.0, Vec::<i32>::new());
+        assert_eq!(info.1, Vec::<i32>::new());
+    }
+
+    #[test]
+    fn test_parse_special() {
+        let mut test_grid: HashMap<&str, (Option<SpecialKind>, i32)> = HashMap::new();
+        test_grid.insert("Show.S00E05.720p.HDTV", (Some(SpecialKind::Special), 5));
+        test_grid.insert("Show Season 00 1080p", (Some(SpecialKind::Special), 0));
+        test_grid.insert("[Group] Title SP01 [1080p].mkv", (Some(SpecialKind::Special), 1));
+        test_grid.insert(
+            "Doctor.Who.2005.Christmas.Special.720p.HDTV",
+            (Some(SpecialKind::Special), 0),
+        );
+        test_grid.insert("Show.Pilot.720p.WEB-DL", (Some(SpecialKind::Pilot), 0));
+        test_grid.insert("[Group] Title OVA - 02 [720p].mkv", (Some(SpecialKind::OVA), 2));
+        test_grid.insert("[Group] Title S2 OVA [1080p].mkv", (Some(SpecialKind::OVA), 0));
+        test_grid.insert("[Group] Title ONA 3 [1080p].mkv", (Some(SpecialKind::ONA), 3));
+        test_grid.insert("[Group] Title OAD [1080p].mkv", (Some(SpecialKind::OAD), 0));
+        test_grid.insert("[Group] Title - NCOP1 [1080p].mkv", (Some(SpecialKind::NCOP), 1));
+        test_grid.insert("[Group] Title - NCED [1080p].mkv", (Some(SpecialKind::NCED), 0));
+        test_grid.insert("Show.S01E01.Pilot.720p.HDTV", (None, 0));
+        test_grid.insert("Show.S02E10.Christmas.Special.720p", (None, 0));
+        test_grid.insert("Movie.2010.Special.Edition.1080p.BluRay", (None, 0));
+        test_grid.insert("Special.Forces.2011.720p.BluRay", (None, 0));
+        test_grid.insert("The.Pilot.2015.1080p.BluRay", (None, 0));
+        test_grid.insert("[Group] Anime - 05 - Special Delivery [1080p].mkv", (None, 0));
+        test_grid.insert("Show.2019.10.21.Special.Guest.720p", (None, 0));
+        test_grid.insert("Show.S01E05.720p.HDTV", (None, 0));
+        test_grid.insert("Show.S10E05.720p.HDTV", (None, 0));
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            assert_eq!(parse_special(key), val);
+        }
+
+        assert!(parse_pack("Show Season 00 1080p", &[0], &[]));
+        assert!(!parse_pack("Show.Christmas.Special.720p", &[0], &[]));
+    }
+
+    #[test]
+    fn test_episode_range() {
+        assert_eq!(range(&[1, 2, 3]), Some(EpisodeRange { start: 1, end: 3 }));
+        assert_eq!(range(&[1, 3]), None);
+        assert_eq!(range(&[1]), None);
+    }
 }
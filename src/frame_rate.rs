@@ -0,0 +1,150 @@
+use super::utils;
+
+use regex::Regex;
+
+//Frame rate as a rational number of frames per second: 23.976 is 24000/1001. Numerator and
+//denominator are None when only the high frame rate flag is known ("HFR")
+#[derive(Serialize, Debug, PartialEq)]
+pub struct FrameRate {
+    pub numerator: Option<i32>,
+    pub denominator: Option<i32>,
+    pub hfr: bool,
+}
+
+lazy_static! {
+    //A dot only marks a decimal rate for NTSC rates: "UFC.250.50fps" is 50 frames per second
+    static ref RE_FPS: Regex = utils::token_regex(
+        r"(?P<rate>23[.,]976?|29[.,]97|47[.,]952|59[.,]94|119[.,]88|\d{2,3}(?:,\d{1,3})?)[\s._-]?(?i:fps)"
+    );
+    //Bare NTSC rates, integer rates are only read with an fps suffix
+    static ref RE_NTSC: Regex =
+        utils::token_regex(r"(?P<rate>23[.,]976?|29[.,]97|47[.,]952|59[.,]94|119[.,]88)");
+    static ref RE_HFR: Regex = utils::token_regex(r"(?i:HFR)");
+}
+
+//Rates of 48 frames per second and above are high frame rates
+const HFR_THRESHOLD: f64 = 48.0;
+
+fn gcd(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+//NTSC rates are whole rates slowed down by 1000/1001: 29.97 is 30000/1001
+fn to_rational(rate: f64) -> (i32, i32) {
+    if rate.fract() == 0.0 {
+        return (rate as i32, 1);
+    }
+
+    let ntsc = (rate * 1001.0 / 1000.0).round();
+    if (ntsc * 1000.0 / 1001.0 - rate).abs() < 0.01 {
+        return (ntsc as i32 * 1000, 1001);
+    }
+
+    let numerator = (rate * 1000.0).round() as i32;
+    let divisor = gcd(numerator, 1000);
+    (numerator / divisor, 1000 / divisor)
+}
+
+fn parse_rate(captures: &regex::Captures) -> f64 {
+    captures["rate"].replace(',', ".").parse::<f64>().unwrap_or(0.0)
+}
+
+//Parse frame rates: "50fps", "60FPS", "23.976", "29.97", "HFR"
+pub fn parse(name: String) -> (Option<FrameRate>, String) {
+    let (hfr, stripped) = utils::find_and_strip_token(&name, vec![RE_HFR.clone()]);
+
+    let rate = [&*RE_FPS, &*RE_NTSC].iter().find_map(|reg| {
+        reg.captures(&stripped)
+            .map(|captures| (parse_rate(&captures), *reg))
+    });
+
+    match rate {
+        Some((rate, reg)) if rate > 0.0 => {
+            let (numerator, denominator) = to_rational(rate);
+            let (_, stripped) = utils::find_and_strip_token(&stripped, vec![reg.clone()]);
+            let frame_rate = FrameRate {
+                numerator: Some(numerator),
+                denominator: Some(denominator),
+                hfr: hfr || rate >= HFR_THRESHOLD,
+            };
+            (Some(frame_rate), stripped)
+        }
+        _ if hfr => {
+            let frame_rate = FrameRate {
+                numerator: None,
+                denominator: None,
+                hfr,
+            };
+            (Some(frame_rate), stripped)
+        }
+        _ => (None, stripped),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parse_frame_rate() {
+        let mut test_grid: HashMap<&str, Option<(i32, i32, bool)>> = HashMap::new();
+        test_grid.insert("F1.2019.Monaco.Race.1080p.50fps.HDTV", Some((50, 1, true)));
+        test_grid.insert("Concert.2019.2160p.60FPS.WEB-DL", Some((60, 1, true)));
+        test_grid.insert("Movie.2019.1080p.23.976.BluRay", Some((24000, 1001, false)));
+        test_grid.insert("Show.S01E01.1080p.29.97fps.HDTV", Some((30000, 1001, false)));
+        test_grid.insert("NBA.2019.720p.59.94.HDTV", Some((60000, 1001, true)));
+        test_grid.insert("Movie.2019.1080p.25fps.BluRay", Some((25, 1, false)));
+        test_grid.insert("The.Hobbit.2012.HFR.48fps.1080p", Some((48, 1, true)));
+        test_grid.insert("UFC.250.50fps.720p", Some((50, 1, true)));
+        test_grid.insert("NBA.2019.12.25.60fps.720p", Some((60, 1, true)));
+        test_grid.insert("F1.2019.Round.12.50fps", Some((50, 1, true)));
+        test_grid.insert("Movie.2019.1080p.BluRay.DD5.1.x264", None);
+        test_grid.insert("Show.S01E01.1080p.HDTV", None);
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let frame_rate = parse(key.to_string()).0;
+
+            assert_eq!(
+                val.map(|(numerator, denominator, hfr)| FrameRate {
+                    numerator: Some(numerator),
+                    denominator: Some(denominator),
+                    hfr
+                }),
+                frame_rate
+            );
+        }
+
+        let hfr_only = parse("The.Hobbit.2012.HFR.1080p.BluRay".to_string()).0;
+        assert_eq!(
+            Some(FrameRate {
+                numerator: None,
+                denominator: None,
+                hfr: true
+            }),
+            hfr_only
+        );
+        assert_eq!(
+            "{\"numerator\":null,\"denominator\":null,\"hfr\":true}",
+            ::serde_json::to_string(&hfr_only.unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_strip_frame_rate() {
+        let mut test_grid: HashMap<&str, &str> = HashMap::new();
+        test_grid.insert("Concert.2019.60FPS.WEB-DL", "Concert.2019.WEB-DL");
+        test_grid.insert("Movie.2019.23.976.BluRay.DD5.1", "Movie.2019.BluRay.DD5.1");
+        test_grid.insert("The.Hobbit.2012.HFR.1080p", "The.Hobbit.2012.1080p");
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            assert_eq!(val, parse(key.to_string()).1);
+        }
+    }
+}
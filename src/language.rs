@@ -0,0 +1,286 @@
+extern crate serde;
+
+use super::title;
+use super::utils;
+
+use regex::Regex;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Language {
+    Arabic,
+    Chinese,
+    Czech,
+    Danish,
+    Dutch,
+    English,
+    Finnish,
+    French,
+    German,
+    Greek,
+    Hebrew,
+    Hindi,
+    Hungarian,
+    Italian,
+    Japanese,
+    Korean,
+    Norwegian,
+    Polish,
+    Portuguese,
+    Russian,
+    Spanish,
+    Swedish,
+    Turkish,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AudioVersion {
+    Original,
+    Dubbed,
+    Multi,
+}
+
+impl serde::Serialize for Language {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match *self {
+            Language::Arabic => serializer.serialize_unit_variant("Language", 0, "ar"),
+            Language::Chinese => serializer.serialize_unit_variant("Language", 0, "zh"),
+            Language::Czech => serializer.serialize_unit_variant("Language", 0, "cs"),
+            Language::Danish => serializer.serialize_unit_variant("Language", 0, "da"),
+            Language::Dutch => serializer.serialize_unit_variant("Language", 0, "nl"),
+            Language::English => serializer.serialize_unit_variant("Language", 0, "en"),
+            Language::Finnish => serializer.serialize_unit_variant("Language", 0, "fi"),
+            Language::French => serializer.serialize_unit_variant("Language", 0, "fr"),
+            Language::German => serializer.serialize_unit_variant("Language", 0, "de"),
+            Language::Greek => serializer.serialize_unit_variant("Language", 0, "el"),
+            Language::Hebrew => serializer.serialize_unit_variant("Language", 0, "he"),
+            Language::Hindi => serializer.serialize_unit_variant("Language", 0, "hi"),
+            Language::Hungarian => serializer.serialize_unit_variant("Language", 0, "hu"),
+            Language::Italian => serializer.serialize_unit_variant("Language", 0, "it"),
+            Language::Japanese => serializer.serialize_unit_variant("Language", 0, "ja"),
+            Language::Korean => serializer.serialize_unit_variant("Language", 0, "ko"),
+            Language::Norwegian => serializer.serialize_unit_variant("Language", 0, "no"),
+            Language::Polish => serializer.serialize_unit_variant("Language", 0, "pl"),
+            Language::Portuguese => serializer.serialize_unit_variant("Language", 0, "pt"),
+            Language::Russian => serializer.serialize_unit_variant("Language", 0, "ru"),
+            Language::Spanish => serializer.serialize_unit_variant("Language", 0, "es"),
+            Language::Swedish => serializer.serialize_unit_variant("Language", 0, "sv"),
+            Language::Turkish => serializer.serialize_unit_variant("Language", 0, "tr"),
+        }
+    }
+}
+
+impl serde::Serialize for AudioVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match *self {
+            AudioVersion::Original => {
+                serializer.serialize_unit_variant("AudioVersion", 0, "original")
+            }
+            AudioVersion::Dubbed => serializer.serialize_unit_variant("AudioVersion", 0, "dubbed"),
+            AudioVersion::Multi => serializer.serialize_unit_variant("AudioVersion", 0, "multi"),
+        }
+    }
+}
+
+lazy_static! {
+    //Dubbed versions tagged with their language: TRUEFRENCH and VFF are french dubs made in France
+    static ref RE_FRENCH_DUB: Vec<Regex> = vec![utils::token_regex(r"TRUEFRENCH|VF[FQI2]?")];
+    static ref LANGUAGE_TABLE: Vec<(Language, Vec<Regex>)> = vec![
+        (Language::Arabic, vec![utils::token_regex(r"ARABIC")]),
+        (Language::Chinese, vec![utils::token_regex(r"CHINESE|MANDARIN|CANTONESE|CHI")]),
+        (Language::Czech, vec![utils::token_regex(r"CZECH")]),
+        (Language::Danish, vec![utils::token_regex(r"DANISH")]),
+        (Language::Dutch, vec![utils::token_regex(r"DUTCH|FLEMISH")]),
+        (Language::English, vec![utils::token_regex(r"ENGLISH|ENG")]),
+        (Language::Finnish, vec![utils::token_regex(r"FINNISH")]),
+        (Language::French, vec![utils::token_regex(r"FRENCH")]),
+        (Language::German, vec![utils::token_regex(r"GERMAN|DEUTSCH|GER")]),
+        (Language::Greek, vec![utils::token_regex(r"GREEK")]),
+        (Language::Hebrew, vec![utils::token_regex(r"HEBREW")]),
+        (Language::Hindi, vec![utils::token_regex(r"HINDI")]),
+        (Language::Hungarian, vec![utils::token_regex(r"HUNGARIAN")]),
+        (Language::Italian, vec![utils::token_regex(r"ITALIAN|[iI]TA")]),
+        (Language::Japanese, vec![utils::token_regex(r"JAPANESE|JAP|JPN")]),
+        (Language::Korean, vec![utils::token_regex(r"KOREAN|KOR")]),
+        (Language::Norwegian, vec![utils::token_regex(r"NORWEGIAN|NOR")]),
+        (Language::Polish, vec![utils::token_regex(r"POLISH")]),
+        (Language::Portuguese, vec![utils::token_regex(r"PORTUGUESE|POR")]),
+        (Language::Russian, vec![utils::token_regex(r"RUSSIAN|RUS")]),
+        (Language::Spanish, vec![utils::token_regex(r"SPANISH|CASTELLANO|LATINO|ESP|SPA")]),
+        (Language::Swedish, vec![utils::token_regex(r"SWEDISH|SWE")]),
+        (Language::Turkish, vec![utils::token_regex(r"TURKISH")]),
+    ];
+    static ref RE_MULTI: Vec<Regex> = vec![
+        utils::token_regex(r"MULTI|MULTi|Multi"),
+        utils::token_regex(r"DUAL[\s._-]?AUDIO|Dual[\s._-]?Audio|DUAL"),
+    ];
+    static ref RE_ORIGINAL: Vec<Regex> = vec![utils::token_regex(r"VOST(FR|A)?|VO|SUBBED|Subbed")];
+    static ref RE_DUBBED: Vec<Regex> = vec![utils::token_regex(r"DUBBED|Dubbed|DUB")];
+    //"GERMAN.DL": dual language release, the named language plus the original one
+    static ref RE_DUAL_LANGUAGE: Regex =
+        Regex::new(r"(?P<language>[A-Z]{3,})[\s._-]DL(?P<after>[\s._\-\[\](){}/]|$)").unwrap();
+}
+
+//Map a short language tag found in release names ("ENG", "nl", "FRENCH") to a language
+pub fn from_tag(tag: &str) -> Option<Language> {
+    match tag.to_lowercase().as_str() {
+        "ar" | "ara" | "arabic" => Some(Language::Arabic),
+        "zh" | "chi" | "chs" | "cht" | "chinese" => Some(Language::Chinese),
+        "cs" | "cz" | "cze" | "czech" => Some(Language::Czech),
+        "da" | "dk" | "dan" | "danish" => Some(Language::Danish),
+        "nl" | "dut" | "dutch" => Some(Language::Dutch),
+        "en" | "eng" | "english" => Some(Language::English),
+        "fi" | "fin" | "finnish" => Some(Language::Finnish),
+        "fr" | "fre" | "fra" | "french" => Some(Language::French),
+        "de" | "ger" | "deu" | "german" => Some(Language::German),
+        "el" | "gr" | "gre" | "greek" => Some(Language::Greek),
+        "he" | "heb" | "hebrew" => Some(Language::Hebrew),
+        "hi" | "hin" | "hindi" => Some(Language::Hindi),
+        "hu" | "hun" | "hungarian" => Some(Language::Hungarian),
+        "it" | "ita" | "italian" => Some(Language::Italian),
+        "ja" | "jp" | "jap" | "jpn" | "japanese" => Some(Language::Japanese),
+        "ko" | "kor" | "korean" => Some(Language::Korean),
+        "no" | "nor" | "norwegian" => Some(Language::Norwegian),
+        "pl" | "pol" | "polish" => Some(Language::Polish),
+        "pt" | "por" | "portuguese" => Some(Language::Portuguese),
+        "ru" | "rus" | "russian" => Some(Language::Russian),
+        "es" | "esp" | "spa" | "spanish" => Some(Language::Spanish),
+        "sv" | "swe" | "swedish" => Some(Language::Swedish),
+        "tr" | "tur" | "turkish" => Some(Language::Turkish),
+        _ => None,
+    }
+}
+
+//Language tags are only looked for after the title boundary so that titles like "The Italian Job"
+//or "French Kiss" are kept
+pub fn parse(name: String) -> (Vec<Language>, Option<AudioVersion>, String) {
+    let boundary = title::title_boundary(&name).unwrap_or(0);
+    let (languages, version, stripped) = parse_languages(name[boundary..].to_string());
+
+    (languages, version, format!("{}{}", &name[..boundary], stripped))
+}
+
+fn parse_languages(name: String) -> (Vec<Language>, Option<AudioVersion>, String) {
+    let mut languages: Vec<Language> = Vec::new();
+    let mut stripped = name.clone();
+
+    let dual_language = RE_DUAL_LANGUAGE
+        .captures(&name)
+        .is_some_and(|x| &x["language"] != "WEB");
+    if dual_language {
+        stripped = RE_DUAL_LANGUAGE
+            .replace(&stripped, "${language}${after}")
+            .to_string();
+    }
+
+    let (french_dub, stripped_name) =
+        utils::find_and_strip_token(&stripped, RE_FRENCH_DUB.to_vec());
+    if french_dub {
+        languages.push(Language::French);
+        stripped = stripped_name;
+    }
+
+    for (language, regex_table) in LANGUAGE_TABLE.iter() {
+        let (matched, stripped_name) = utils::find_and_strip_token(&stripped, regex_table.to_vec());
+        if matched {
+            if !languages.contains(language) {
+                languages.push(*language);
+            }
+            stripped = stripped_name;
+        }
+    }
+
+    let (multi, stripped_name) = utils::find_and_strip_token(&stripped, RE_MULTI.to_vec());
+    stripped = stripped_name;
+    let (original, stripped_name) = utils::find_and_strip_token(&stripped, RE_ORIGINAL.to_vec());
+    stripped = stripped_name;
+    let (dubbed, stripped_name) = utils::find_and_strip_token(&stripped, RE_DUBBED.to_vec());
+    stripped = stripped_name;
+
+    let version = if multi || dual_language || languages.len() > 1 {
+        Some(AudioVersion::Multi)
+    } else if original {
+        Some(AudioVersion::Original)
+    } else if dubbed || french_dub {
+        Some(AudioVersion::Dubbed)
+    } else {
+        None
+    };
+
+    (languages, version, stripped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parse_language() {
+        let mut test_grid: HashMap<&str, (Vec<Language>, Option<AudioVersion>)> = HashMap::new();
+        test_grid.insert("Movie.2019.FRENCH.1080p", (vec![Language::French], None));
+        test_grid.insert("THE.ITALIAN.JOB.2003.1080p.BluRay", (vec![], None));
+        test_grid.insert("FRENCH.KISS.1995.720p", (vec![], None));
+        test_grid.insert("DUAL.2022.1080p.WEB", (vec![], None));
+        test_grid.insert(
+            "Movie.2019.TRUEFRENCH.1080p",
+            (vec![Language::French], Some(AudioVersion::Dubbed)),
+        );
+        test_grid.insert(
+            "Movie.2019.VFF.1080p",
+            (vec![Language::French], Some(AudioVersion::Dubbed)),
+        );
+        test_grid.insert(
+            "Movie.2019.MULTi.VFF.1080p",
+            (vec![Language::French], Some(AudioVersion::Multi)),
+        );
+        test_grid.insert(
+            "Movie.2019.MULTi.1080p",
+            (vec![], Some(AudioVersion::Multi)),
+        );
+        test_grid.insert(
+            "Show.S01E01.VOSTFR.720p",
+            (vec![], Some(AudioVersion::Original)),
+        );
+        test_grid.insert("Movie.2019.iTA.720p", (vec![Language::Italian], None));
+        test_grid.insert(
+            "Movie.2019.GERMAN.DL.1080p.WEB-DL",
+            (vec![Language::German], Some(AudioVersion::Multi)),
+        );
+        test_grid.insert(
+            "Movie.2019.iTA.ENG.1080p",
+            (
+                vec![Language::English, Language::Italian],
+                Some(AudioVersion::Multi),
+            ),
+        );
+        test_grid.insert("The.French.Connection.1971.1080p.WEB-DL", (vec![], None));
+        test_grid.insert("Movie.2019.1080p.WEB-DL.DD5.1", (vec![], None));
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let (languages, version, _) = parse(key.to_string());
+
+            assert_eq!(val.0, languages);
+            assert_eq!(val.1, version);
+        }
+    }
+
+    #[test]
+    fn test_strip_language() {
+        let mut test_grid: HashMap<&str, &str> = HashMap::new();
+        test_grid.insert("Movie.2019.MULTi.TRUEFRENCH.1080p", "Movie.2019.1080p");
+        test_grid.insert("Movie.2019.GERMAN.DL.1080p", "Movie.2019.1080p");
+        test_grid.insert("Movie FRENCH", "Movie");
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            assert_eq!(val, parse(key.to_string()).2);
+        }
+    }
+}
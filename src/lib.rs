@@ -1,29 +1,47 @@
+extern crate crc32fast;
 extern crate regex;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+#[cfg(test)]
+extern crate serde_json;
 #[macro_use]
 extern crate lazy_static;
 use std::ffi::OsStr;
+use std::io;
 use std::path::Path;
 
 mod utils;
 
+mod air_date;
 mod audio;
 pub mod configuration;
 mod container;
+mod country;
+mod crc32;
+mod disc_type;
+mod edition;
 mod episode;
+mod frame_rate;
+mod language;
+mod part;
 mod quality;
 mod release_group;
 mod release_type;
+mod revision;
+mod stereo_3d;
+mod streaming_service;
+mod subtitle;
 mod title;
 mod video_codec;
+mod website;
 mod year;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum MediaType {
     Movie,
     Episode,
+    DailyEpisode,
 }
 
 impl serde::Serialize for MediaType {
@@ -34,74 +52,209 @@ impl serde::Serialize for MediaType {
         match *self {
             MediaType::Movie => serializer.serialize_unit_variant("MediaType", 0, "movie"),
             MediaType::Episode => serializer.serialize_unit_variant("MediaType", 0, "episode"),
+            MediaType::DailyEpisode => {
+                serializer.serialize_unit_variant("MediaType", 0, "daily_episode")
+            }
         }
     }
 }
 
 #[derive(Serialize, Debug, PartialEq)]
 pub struct MediaInfo {
+    absolute_episode: i32,
+    alternative_titles: Vec<String>,
+    air_date: Option<air_date::AirDate>,
     audio_channels: Option<audio::AudioChannels>,
     audio_codec: Option<audio::AudioCodec>,
+    audio_profile: Option<audio::AudioProfile>,
+    audio_tracks: Vec<audio::AudioTrack>,
+    audio_version: Option<language::AudioVersion>,
+    bit_depth: i32,
     container: Option<container::Container>,
+    country: Option<country::Country>,
+    crc32: Option<String>,
+    disc: i32,
+    disc_type: Option<disc_type::DiscType>,
+    edition: Option<edition::Edition>,
     episode: i32,
+    episode_range: Option<episode::EpisodeRange>,
+    episode_title: String,
+    episodes: Vec<i32>,
+    frame_rate: Option<frame_rate::FrameRate>,
+    hdr_formats: Vec<video_codec::HdrFormat>,
+    indexer_tag: Option<String>,
+    is_encode: bool,
+    is_pack: bool,
+    languages: Vec<language::Language>,
     media_type: MediaType,
+    part: i32,
     quality: Option<quality::Quality>,
     release_group: String,
     release_type: Option<release_type::ReleaseType>,
+    remux: bool,
+    revision: Option<revision::Revision>,
     season: i32,
+    seasons: Vec<i32>,
+    special_kind: Option<episode::SpecialKind>,
+    stereo_3d: Option<stereo_3d::Stereo3D>,
+    streaming_service: Option<streaming_service::StreamingService>,
+    subtitles: Option<subtitle::Subtitles>,
     title: String,
     raw: String,
     video_codec: Option<video_codec::VideoCodec>,
+    website: Option<String>,
     year: i32,
 }
 
 pub fn parse(name: &str, options: Option<configuration::CliOptions>) -> MediaInfo {
     let options: configuration::CliOptions = options.unwrap_or_default();
+    let raw = name;
+    //" / " separates alternative titles ("Ghost in the Shell / Koukaku Kidoutai"), not path components
+    let normalized_name = name.replace(" / ", " | ");
+    let (website, indexer_tag, name_without_website) = website::parse(normalized_name);
+    let name: &str = &name_without_website;
+
+    let (subtitles, name_without_subtitles) = subtitle::parse(name.to_string());
+
+    let mut file_path: Vec<&OsStr> = Path::new(&name_without_subtitles).iter().collect();
+    let filename_from_path = file_path.pop().unwrap().to_str().unwrap();
 
-    let mut file_path: Vec<&OsStr> = Path::new(name).iter().collect();
-    let filename_from_path = file_path.pop().clone().unwrap().to_str().unwrap();
+    let filename = edition::strip_plex(filename_from_path);
 
-    let (release_type, stripped) = release_type::parse(filename_from_path.to_string());
-    let (video_codec, stripped) = video_codec::parse(stripped);
-    let (audio_codec, audio_channels, stripped) = audio::parse(stripped);
+    let disc_type = disc_type::parse(&filename);
+    let (stereo_3d, stripped) = stereo_3d::parse(filename);
+    let (part, disc, stripped) = part::parse(stripped);
+    let (crc32, stripped) = crc32::parse(stripped);
+    let (release_type, streaming_service, stripped) = release_type::parse(stripped);
+    let remux = release_type == Some(release_type::ReleaseType::Remux);
+    let (video_codec, is_encode, stripped) = video_codec::parse(stripped);
+    let (hdr_formats, stripped) = video_codec::parse_hdr(stripped);
+    let (bit_depth, stripped) = video_codec::parse_bit_depth(stripped);
+    //Frame rates such as "59.94" would otherwise be read as audio channel layouts
+    let (frame_rate, stripped) = frame_rate::parse(stripped);
+    let (audio_tracks, audio_codec, audio_profile, audio_channels, stripped) =
+        audio::parse(stripped);
     let (container, stripped) = container::parse(stripped);
-    let (season, episode, _stripped) = if let Some("movie") = options.media_type {
-        (0, 0, name.to_string())
+    let (air_date, name_without_date) = if let Some("movie") = options.media_type {
+        (None, name.to_string())
     } else {
-        episode::parse(name.to_string())
+        air_date::parse(name.to_string(), options.date_order)
+    };
+    let (seasons, episodes, _stripped) = if let Some("movie") = options.media_type {
+        (Vec::new(), Vec::new(), name.to_string())
+    } else {
+        episode::parse(name_without_date)
+    };
+    let (special_kind, special_episode) = match options.media_type {
+        Some("movie") => (None, 0),
+        _ => episode::parse_special(name),
     };
-    let (quality, stripped) = quality::parse(stripped);
+    //Specials belong to season 0, their number replaces any regular or absolute episode number
+    let (seasons, episodes) = match special_kind {
+        Some(_) if special_episode != 0 => (vec![0], vec![special_episode]),
+        Some(_) => (vec![0], episodes),
+        None => (seasons, episodes),
+    };
+    let absolute_episode = match options.media_type {
+        Some("movie") => 0,
+        _ if special_kind.is_some() => 0,
+        _ => episode::parse_absolute(name),
+    };
+    let season = seasons.first().cloned().unwrap_or(0);
+    let episode = episodes.first().cloned().unwrap_or(0);
+    let episode_range = episode::range(&episodes);
+    let is_pack = match options.media_type {
+        Some("movie") => false,
+        _ => episode::parse_pack(name, &seasons, &episodes),
+    };
+    let name_without_technical = stripped;
+    let (quality, stripped) = quality::parse(name_without_technical.clone());
+    let (revision, stripped) = revision::parse(stripped);
     let (release_group, _stripped) = release_group::parse(&stripped);
     let year = year::parse(name);
 
     let media_type: MediaType = match options.media_type {
         Some("movie") => MediaType::Movie,
         Some("episode") => MediaType::Episode,
-        _ => match (season, episode) {
-            (0, 0) => MediaType::Movie,
+        _ => match (season, episode, is_pack) {
+            (0, 0, false) if air_date.is_some() => MediaType::DailyEpisode,
+            (0, 0, false) if absolute_episode != 0 => MediaType::Episode,
+            (0, 0, false) if special_kind.is_some() => MediaType::Episode,
+            (0, 0, false) => MediaType::Movie,
             _ => MediaType::Episode,
         },
     };
 
-    let title = title::parse(name, Some(media_type));
+    let (languages, audio_version, name_without_languages) =
+        language::parse(name_without_subtitles.clone());
+    let (edition, name_without_edition) = edition::parse(name_without_languages);
+    //A name made only of tags ("VOSTFR") is its own title
+    let title_name: &str = if name_without_edition.chars().any(char::is_alphanumeric) {
+        &name_without_edition
+    } else {
+        &name_without_subtitles
+    };
+
+    let (title, alternative_titles) = title::parse(title_name, Some(media_type));
+    let country = title::parse_country(title_name);
+    let episode_title = match media_type {
+        MediaType::Movie => String::new(),
+        _ => title::parse_episode_title(title_name, &name_without_technical),
+    };
 
     MediaInfo {
+        absolute_episode,
+        alternative_titles,
+        air_date,
         audio_channels,
         audio_codec,
+        audio_profile,
+        audio_tracks,
+        audio_version,
+        bit_depth,
         container,
+        country,
+        crc32,
+        disc,
+        disc_type,
+        edition,
         episode,
+        episode_range,
+        episode_title,
+        episodes,
+        frame_rate,
+        hdr_formats,
+        indexer_tag,
+        is_encode,
+        is_pack,
+        languages,
         media_type,
+        part,
         quality,
         release_group,
         release_type,
+        remux,
+        revision,
         season,
+        seasons,
+        special_kind,
+        stereo_3d,
+        streaming_service,
+        subtitles,
         title,
         video_codec,
+        website,
         year,
-        raw: name.to_string(),
+        raw: raw.to_string(),
     }
 }
 
+//Compute the CRC32 checksum of a local file and compare it to the one found in its name
+//("[Group] Title - 05 [A1B2C3D4].mkv"). Returns None when the file name carries no checksum
+pub fn verify_crc32<P: AsRef<Path>>(path: P) -> io::Result<Option<bool>> {
+    crc32::verify(path)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,16 +269,46 @@ mod tests {
             MediaInfo {
                 title: "2047 - Sights of Death".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Movie,
                 quality: Some(quality::Quality::Q720),
-                release_type: Some(release_type::ReleaseType::BluRayRip),
+                release_type: Some(release_type::ReleaseType::BDRip),
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: None,
                 audio_channels: None,
                 release_group: "YIFY".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "2047 - Sights of Death (2014) 720p BrRip x264 - YIFY".to_string(),
             },
         );
@@ -134,7 +317,11 @@ mod tests {
             MediaInfo {
                 title: "The Flash".to_string(),
                 season: 1,
+                seasons: vec![1],
                 episode: 4,
+                episode_range: None,
+                episodes: vec![4],
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Episode,
                 quality: None,
@@ -142,8 +329,34 @@ mod tests {
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: None,
                 audio_channels: None,
-                release_group: "FUM[ettv]".to_string(),
+                release_group: "FUM".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: Some("ettv".to_string()),
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "The Flash 2014 S01E04 HDTV x264-FUM[ettv]".to_string(),
             },
         );
@@ -152,7 +365,11 @@ mod tests {
             MediaInfo {
                 title: "The Walking Dead".to_string(),
                 season: 5,
+                seasons: vec![5],
                 episode: 3,
+                episode_range: None,
+                episodes: vec![3],
+                is_pack: false,
                 year: 0,
                 media_type: MediaType::Episode,
                 quality: Some(quality::Quality::Q720),
@@ -160,8 +377,34 @@ mod tests {
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: None,
                 audio_channels: None,
-                release_group: "ASAP[ettv]".to_string(),
+                release_group: "ASAP".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: Some("ettv".to_string()),
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "The Walking Dead S05E03 720p HDTV x264-ASAP[ettv]".to_string(),
             },
         );
@@ -170,16 +413,46 @@ mod tests {
             MediaInfo {
                 title: "Hercules".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Movie,
                 quality: Some(quality::Quality::Q1080),
-                release_type: Some(release_type::ReleaseType::BluRayRip),
+                release_type: Some(release_type::ReleaseType::BDRip),
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: None,
                 audio_channels: None,
                 release_group: "YIFY".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Hercules (2014) 1080p BrRip H264 - YIFY".to_string(),
             },
         );
@@ -188,16 +461,46 @@ mod tests {
             MediaInfo {
                 title: "Dawn of the Planet of the Apes".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Movie,
                 quality: None,
-                release_type: Some(release_type::ReleaseType::HDTV),
+                release_type: Some(release_type::ReleaseType::HDRip),
                 video_codec: Some(video_codec::VideoCodec::XVID),
                 audio_codec: None,
                 audio_channels: None,
                 release_group: "EVO".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Dawn.of.the.Planet.of.the.Apes.2014.HDRip.XViD-EVO".to_string(),
             },
         );
@@ -206,7 +509,11 @@ mod tests {
             MediaInfo {
                 title: "The Big Bang Theory".to_string(),
                 season: 8,
+                seasons: vec![8],
                 episode: 6,
+                episode_range: None,
+                episodes: vec![6],
+                is_pack: false,
                 year: 0,
                 media_type: MediaType::Episode,
                 quality: None,
@@ -214,8 +521,34 @@ mod tests {
                 video_codec: Some(video_codec::VideoCodec::XVID),
                 audio_codec: None,
                 audio_channels: None,
-                release_group: "LOL [eztv]".to_string(),
+                release_group: "LOL".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: Some("eztv".to_string()),
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "The Big Bang Theory S08E06 HDTV XviD-LOL [eztv]".to_string(),
             },
         );
@@ -224,16 +557,46 @@ mod tests {
             MediaInfo {
                 title: "22 Jump Street".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Movie,
                 quality: Some(quality::Quality::Q720),
-                release_type: Some(release_type::ReleaseType::BluRayRip),
+                release_type: Some(release_type::ReleaseType::BDRip),
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: None,
                 audio_channels: None,
                 release_group: "YIFY".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "22 Jump Street (2014) 720p BrRip x264 - YIFY".to_string(),
             },
         );
@@ -242,16 +605,51 @@ mod tests {
             MediaInfo {
                 title: "Hercules".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Movie,
                 quality: Some(quality::Quality::Q1080),
                 release_type: Some(release_type::ReleaseType::WEBDL),
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: Some(audio::AudioCodec::DolbyDigital),
-                audio_channels: Some(audio::AudioChannels::Chan51),
+                audio_channels: Some(audio::AudioChannels::CHAN51),
                 release_group: "RARBG".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: Some(edition::Edition::Extended),
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: vec![audio::AudioTrack {
+                    codec: Some(audio::AudioCodec::DolbyDigital),
+                    profile: None,
+                    channels: Some(audio::AudioChannels::CHAN51),
+                    language: None,
+                }],
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Hercules.2014.EXTENDED.1080p.WEB-DL.DD5.1.H264-RARBG".to_string(),
             },
         );
@@ -260,16 +658,46 @@ mod tests {
             MediaInfo {
                 title: "Hercules".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Movie,
                 quality: None,
-                release_type: Some(release_type::ReleaseType::HDTV),
+                release_type: Some(release_type::ReleaseType::HDRip),
                 video_codec: Some(video_codec::VideoCodec::XVID),
                 audio_codec: None,
                 audio_channels: None,
-                release_group: "juggs[ETRG]".to_string(),
+                release_group: "juggs".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: Some(edition::Edition::Extended),
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: Some("ETRG".to_string()),
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Hercules.2014.EXTENDED.HDRip.XViD-juggs[ETRG]".to_string(),
             },
         );
@@ -278,7 +706,11 @@ mod tests {
             MediaInfo {
                 title: "Hercules".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Movie,
                 quality: None,
@@ -288,6 +720,32 @@ mod tests {
                 audio_channels: None,
                 release_group: "MAX".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Hercules (2014) WEBDL DVDRip XviD-MAX".to_string(),
             },
         );
@@ -296,7 +754,11 @@ mod tests {
             MediaInfo {
                 title: "WWE Hell in a Cell".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Movie,
                 quality: None,
@@ -306,6 +768,32 @@ mod tests {
                 audio_channels: None,
                 release_group: "WD -={SPARROW}=-".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "WWE Hell in a Cell 2014 PPV WEB-DL x264-WD -={SPARROW}=-".to_string(),
             },
         );
@@ -314,7 +802,11 @@ mod tests {
             MediaInfo {
                 title: "Marvels Agents of S H I E L D".to_string(),
                 season: 2,
+                seasons: vec![2],
                 episode: 5,
+                episode_range: None,
+                episodes: vec![5],
+                is_pack: false,
                 year: 0,
                 media_type: MediaType::Episode,
                 quality: None,
@@ -322,8 +814,34 @@ mod tests {
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: None,
                 audio_channels: None,
-                release_group: "KILLERS [eztv]".to_string(),
+                release_group: "KILLERS".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: Some("eztv".to_string()),
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Marvels Agents of S H I E L D S02E05 HDTV x264-KILLERS [eztv]".to_string(),
             },
         );
@@ -332,16 +850,51 @@ mod tests {
             MediaInfo {
                 title: "X-Men Days of Future Past".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Movie,
                 quality: Some(quality::Quality::Q1080),
                 release_type: Some(release_type::ReleaseType::WEBDL),
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: Some(audio::AudioCodec::DolbyDigital),
-                audio_channels: Some(audio::AudioChannels::Chan51),
+                audio_channels: Some(audio::AudioChannels::CHAN51),
                 release_group: "RARBG".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: vec![audio::AudioTrack {
+                    codec: Some(audio::AudioCodec::DolbyDigital),
+                    profile: None,
+                    channels: Some(audio::AudioChannels::CHAN51),
+                    language: None,
+                }],
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "X-Men.Days.of.Future.Past.2014.1080p.WEB-DL.DD5.1.H264-RARBG".to_string(),
             },
         );
@@ -350,7 +903,11 @@ mod tests {
             MediaInfo {
                 title: "Guardians Of The Galaxy".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Movie,
                 quality: Some(quality::Quality::Q720),
@@ -360,6 +917,32 @@ mod tests {
                 audio_channels: None,
                 release_group: "JYK".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Guardians Of The Galaxy 2014 R6 720p HDCAM x264-JYK".to_string(),
             },
         );
@@ -368,16 +951,51 @@ mod tests {
             MediaInfo {
                 title: "Marvel's Agents of S H I E L D".to_string(),
                 season: 2,
+                seasons: vec![2],
                 episode: 1,
+                episode_range: None,
+                episodes: vec![1],
+                is_pack: false,
                 year: 0,
                 media_type: MediaType::Episode,
                 quality: Some(quality::Quality::Q1080),
                 release_type: Some(release_type::ReleaseType::WEBDL),
                 video_codec: None,
                 audio_codec: Some(audio::AudioCodec::DolbyDigital),
-                audio_channels: Some(audio::AudioChannels::Chan51),
+                audio_channels: Some(audio::AudioChannels::CHAN51),
                 release_group: "".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: vec![audio::AudioTrack {
+                    codec: Some(audio::AudioCodec::DolbyDigital),
+                    profile: None,
+                    channels: Some(audio::AudioChannels::CHAN51),
+                    language: None,
+                }],
+                episode_title: "Shadows".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Marvel's.Agents.of.S.H.I.E.L.D.S02E01.Shadows.1080p.WEB-DL.DD5.1".to_string(),
             },
         );
@@ -386,7 +1004,11 @@ mod tests {
             MediaInfo {
                 title: "Marvels Agents of S H I E L D".to_string(),
                 season: 2,
+                seasons: vec![2],
                 episode: 6,
+                episode_range: None,
+                episodes: vec![6],
+                is_pack: false,
                 year: 0,
                 media_type: MediaType::Episode,
                 quality: None,
@@ -394,8 +1016,34 @@ mod tests {
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: None,
                 audio_channels: None,
-                release_group: "KILLERS[ettv]".to_string(),
+                release_group: "KILLERS".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: Some("ettv".to_string()),
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Marvels Agents of S.H.I.E.L.D. S02E06 HDTV x264-KILLERS[ettv]".to_string(),
             },
         );
@@ -404,16 +1052,51 @@ mod tests {
             MediaInfo {
                 title: "The Walking Dead".to_string(),
                 season: 5,
+                seasons: vec![5],
                 episode: 3,
+                episode_range: None,
+                episodes: vec![3],
+                is_pack: false,
                 year: 0,
                 media_type: MediaType::Episode,
                 quality: Some(quality::Quality::Q1080),
                 release_type: Some(release_type::ReleaseType::WEBDL),
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: Some(audio::AudioCodec::DolbyDigital),
-                audio_channels: Some(audio::AudioChannels::Chan51),
-                release_group: "Cyphanix[rartv]".to_string(),
+                audio_channels: Some(audio::AudioChannels::CHAN51),
+                release_group: "Cyphanix".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: vec![audio::AudioTrack {
+                    codec: Some(audio::AudioCodec::DolbyDigital),
+                    profile: None,
+                    channels: Some(audio::AudioChannels::CHAN51),
+                    language: None,
+                }],
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: Some("rartv".to_string()),
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "The.Walking.Dead.S05E03.1080p.WEB-DL.DD5.1.H.264-Cyphanix[rartv]".to_string(),
             },
         );
@@ -422,7 +1105,11 @@ mod tests {
             MediaInfo {
                 title: "Brave".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2012,
                 media_type: MediaType::Movie,
                 quality: None,
@@ -432,6 +1119,32 @@ mod tests {
                 audio_channels: None,
                 release_group: "UNiQUE".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Brave.2012.R5.DVDRip.XViD.LiNE-UNiQUE".to_string(),
             },
         );
@@ -440,16 +1153,46 @@ mod tests {
             MediaInfo {
                 title: "Lets Be Cops".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Movie,
                 quality: None,
-                release_type: Some(release_type::ReleaseType::BluRayRip),
+                release_type: Some(release_type::ReleaseType::BDRip),
                 video_codec: Some(video_codec::VideoCodec::XVID),
                 audio_codec: None,
                 audio_channels: None,
-                release_group: "juggs[ETRG]".to_string(),
+                release_group: "juggs".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: Some("ETRG".to_string()),
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Lets.Be.Cops.2014.BRRip.XViD-juggs[ETRG]".to_string(),
             },
         );
@@ -458,7 +1201,11 @@ mod tests {
             MediaInfo {
                 title: "Downton Abbey".to_string(),
                 season: 5,
+                seasons: vec![5],
                 episode: 6,
+                episode_range: None,
+                episodes: vec![6],
+                is_pack: false,
                 year: 0,
                 media_type: MediaType::Episode,
                 quality: None,
@@ -466,8 +1213,34 @@ mod tests {
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: None,
                 audio_channels: None,
-                release_group: "FoV [eztv]".to_string(),
+                release_group: "FoV".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: Some("eztv".to_string()),
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Downton Abbey 5x06 HDTV x264-FoV [eztv]".to_string(),
             },
         );
@@ -476,16 +1249,56 @@ mod tests {
             MediaInfo {
                 title: "Annabelle".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Movie,
                 quality: None,
-                release_type: Some(release_type::ReleaseType::HDTV),
+                release_type: Some(release_type::ReleaseType::HDRip),
                 video_codec: Some(video_codec::VideoCodec::XVID),
                 audio_codec: Some(audio::AudioCodec::DolbyDigital),
                 audio_channels: None,
-                release_group: "juggs[ETRG]".to_string(),
+                release_group: "juggs".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: Some(subtitle::Subtitles {
+                    languages: Vec::new(),
+                    multi: false,
+                    hardcoded: true,
+                    soft: false,
+                }),
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: vec![audio::AudioTrack {
+                    codec: Some(audio::AudioCodec::DolbyDigital),
+                    profile: None,
+                    channels: None,
+                    language: None,
+                }],
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: Some("ETRG".to_string()),
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Annabelle.2014.HC.HDRip.XViD.AC3-juggs[ETRG]".to_string(),
             },
         );
@@ -494,16 +1307,51 @@ mod tests {
             MediaInfo {
                 title: "Lucy".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Movie,
                 quality: None,
-                release_type: Some(release_type::ReleaseType::HDTV),
+                release_type: Some(release_type::ReleaseType::HDRip),
                 video_codec: Some(video_codec::VideoCodec::XVID),
                 audio_codec: None,
                 audio_channels: None,
-                release_group: "juggs[ETRG]".to_string(),
+                release_group: "juggs".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: Some(subtitle::Subtitles {
+                    languages: Vec::new(),
+                    multi: false,
+                    hardcoded: true,
+                    soft: false,
+                }),
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: Some("ETRG".to_string()),
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Lucy.2014.HC.HDRip.XViD-juggs[ETRG]".to_string(),
             },
         );
@@ -512,7 +1360,11 @@ mod tests {
             MediaInfo {
                 title: "The Flash".to_string(),
                 season: 1,
+                seasons: vec![1],
                 episode: 4,
+                episode_range: None,
+                episodes: vec![4],
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Episode,
                 quality: None,
@@ -520,8 +1372,34 @@ mod tests {
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: None,
                 audio_channels: None,
-                release_group: "FUM[ettv]".to_string(),
+                release_group: "FUM".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: Some("ettv".to_string()),
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "The Flash 2014 S01E04 HDTV x264-FUM[ettv]".to_string(),
             },
         );
@@ -530,7 +1408,11 @@ mod tests {
             MediaInfo {
                 title: "South Park".to_string(),
                 season: 18,
+                seasons: vec![18],
                 episode: 5,
+                episode_range: None,
+                episodes: vec![5],
+                is_pack: false,
                 year: 0,
                 media_type: MediaType::Episode,
                 quality: None,
@@ -538,8 +1420,34 @@ mod tests {
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: None,
                 audio_channels: None,
-                release_group: "KILLERS [eztv]".to_string(),
+                release_group: "KILLERS".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: Some("eztv".to_string()),
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "South Park S18E05 HDTV x264-KILLERS [eztv]".to_string(),
             },
         );
@@ -548,7 +1456,11 @@ mod tests {
             MediaInfo {
                 title: "The Simpsons".to_string(),
                 season: 26,
+                seasons: vec![26],
                 episode: 5,
+                episode_range: None,
+                episodes: vec![5],
+                is_pack: false,
                 year: 0,
                 media_type: MediaType::Episode,
                 quality: None,
@@ -556,8 +1468,38 @@ mod tests {
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: None,
                 audio_channels: None,
-                release_group: "LOL [eztv]".to_string(),
+                release_group: "LOL".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: Some(revision::Revision {
+                    version: 2,
+                    kind: revision::RevisionKind::Proper,
+                    real: false,
+                }),
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: Some("eztv".to_string()),
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "The Simpsons S26E05 HDTV x264 PROPER-LOL [eztv]".to_string(),
             },
         );
@@ -566,7 +1508,11 @@ mod tests {
             MediaInfo {
                 title: "Two and a Half Men".to_string(),
                 season: 12,
+                seasons: vec![12],
                 episode: 1,
+                episode_range: None,
+                episodes: vec![1],
+                is_pack: false,
                 year: 0,
                 media_type: MediaType::Episode,
                 quality: None,
@@ -574,8 +1520,38 @@ mod tests {
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: None,
                 audio_channels: None,
-                release_group: "LOL [eztv]".to_string(),
+                release_group: "LOL".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: Some(revision::Revision {
+                    version: 2,
+                    kind: revision::RevisionKind::Repack,
+                    real: false,
+                }),
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: Some("eztv".to_string()),
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Two and a Half Men S12E01 HDTV x264 REPACK-LOL [eztv]".to_string(),
             },
         );
@@ -584,34 +1560,567 @@ mod tests {
             MediaInfo {
                 title: "Dinosaur 13".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Movie,
                 quality: None,
-                release_type: Some(release_type::ReleaseType::WEBDL),
+                release_type: Some(release_type::ReleaseType::WEBRip),
                 video_codec: Some(video_codec::VideoCodec::XVID),
                 audio_codec: Some(audio::AudioCodec::DolbyDigital),
                 audio_channels: None,
                 release_group: "".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: vec![audio::AudioTrack {
+                    codec: Some(audio::AudioCodec::DolbyDigital),
+                    profile: None,
+                    channels: None,
+                    language: None,
+                }],
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Dinosaur 13 2014 WEBrip XviD AC3 MiLLENiUM".to_string(),
             },
         );
+        test_grid.insert(
+            "Dune.2021.2160p.UHD.BluRay.DV.HDR10.10bit.DD5.1.x265-GRP",
+            MediaInfo {
+                title: "Dune".to_string(),
+                season: 0,
+                seasons: Vec::new(),
+                episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
+                year: 2021,
+                media_type: MediaType::Movie,
+                quality: Some(quality::Quality::Q2160),
+                release_type: Some(release_type::ReleaseType::UHDBluRay),
+                video_codec: Some(video_codec::VideoCodec::H265),
+                audio_codec: Some(audio::AudioCodec::DolbyDigital),
+                audio_channels: Some(audio::AudioChannels::CHAN51),
+                release_group: "GRP".to_string(),
+                container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 10,
+                hdr_formats: vec![
+                    video_codec::HdrFormat::DolbyVision,
+                    video_codec::HdrFormat::HDR10,
+                ],
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: vec![audio::AudioTrack {
+                    codec: Some(audio::AudioCodec::DolbyDigital),
+                    profile: None,
+                    channels: Some(audio::AudioChannels::CHAN51),
+                    language: None,
+                }],
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "Dune.2021.2160p.UHD.BluRay.DV.HDR10.10bit.DD5.1.x265-GRP".to_string(),
+            },
+        );
+        test_grid.insert(
+            "The.Office.US.S01E01.720p.HDTV.x264-GRP",
+            MediaInfo {
+                title: "The Office".to_string(),
+                season: 1,
+                seasons: vec![1],
+                episode: 1,
+                episode_range: None,
+                episodes: vec![1],
+                is_pack: false,
+                year: 0,
+                media_type: MediaType::Episode,
+                quality: Some(quality::Quality::Q720),
+                release_type: Some(release_type::ReleaseType::HDTV),
+                video_codec: Some(video_codec::VideoCodec::H264),
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "GRP".to_string(),
+                container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: Some(country::Country::UnitedStates),
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "The.Office.US.S01E01.720p.HDTV.x264-GRP".to_string(),
+            },
+        );
+        test_grid.insert(
+            "[HorribleSubs] Akame ga Kill! OVA - 02 [720p].mkv",
+            MediaInfo {
+                title: "Akame ga Kill!".to_string(),
+                season: 0,
+                seasons: vec![0],
+                episode: 2,
+                episode_range: None,
+                episodes: vec![2],
+                is_pack: false,
+                year: 0,
+                media_type: MediaType::Episode,
+                quality: Some(quality::Quality::Q720),
+                release_type: None,
+                video_codec: None,
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "HorribleSubs".to_string(),
+                container: Some(container::Container::Matroska),
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: Some(episode::SpecialKind::OVA),
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "[HorribleSubs] Akame ga Kill! OVA - 02 [720p].mkv".to_string(),
+            },
+        );
+        test_grid.insert(
+            "Step.Up.3D.2010.1080p.BluRay.Half-SBS.x264-GRP",
+            MediaInfo {
+                title: "Step Up 3D".to_string(),
+                season: 0,
+                seasons: Vec::new(),
+                episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
+                year: 2010,
+                media_type: MediaType::Movie,
+                quality: Some(quality::Quality::Q1080),
+                release_type: Some(release_type::ReleaseType::BluRayRip),
+                video_codec: Some(video_codec::VideoCodec::H264),
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "GRP".to_string(),
+                container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: Some(stereo_3d::Stereo3D::HalfSideBySide),
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "Step.Up.3D.2010.1080p.BluRay.Half-SBS.x264-GRP".to_string(),
+            },
+        );
+        test_grid.insert(
+            "www.Torrenting.com - Inception.2010.720p.BluRay.x264-GRP",
+            MediaInfo {
+                title: "Inception".to_string(),
+                season: 0,
+                seasons: Vec::new(),
+                episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
+                year: 2010,
+                media_type: MediaType::Movie,
+                quality: Some(quality::Quality::Q720),
+                release_type: Some(release_type::ReleaseType::BluRayRip),
+                video_codec: Some(video_codec::VideoCodec::H264),
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "GRP".to_string(),
+                container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: Some("www.Torrenting.com".to_string()),
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "www.Torrenting.com - Inception.2010.720p.BluRay.x264-GRP".to_string(),
+            },
+        );
+        test_grid.insert(
+            "NBA.2019.12.25.Lakers.vs.Clippers.720p.59.94.HDTV.AAC2.0.x264-GRP",
+            MediaInfo {
+                title: "NBA".to_string(),
+                season: 0,
+                seasons: Vec::new(),
+                episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
+                year: 2019,
+                media_type: MediaType::DailyEpisode,
+                quality: Some(quality::Quality::Q720),
+                release_type: Some(release_type::ReleaseType::HDTV),
+                video_codec: Some(video_codec::VideoCodec::H264),
+                audio_codec: Some(audio::AudioCodec::AAC),
+                audio_channels: Some(audio::AudioChannels::STEREO),
+                release_group: "GRP".to_string(),
+                container: None,
+                air_date: Some(air_date::AirDate {
+                    year: 2019,
+                    month: 12,
+                    day: 25,
+                }),
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: vec![audio::AudioTrack {
+                    codec: Some(audio::AudioCodec::AAC),
+                    profile: None,
+                    channels: Some(audio::AudioChannels::STEREO),
+                    language: None,
+                }],
+                episode_title: "Lakers vs Clippers".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: Some(frame_rate::FrameRate {
+                    numerator: Some(60000),
+                    denominator: Some(1001),
+                    hfr: true,
+                }),
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "NBA.2019.12.25.Lakers.vs.Clippers.720p.59.94.HDTV.AAC2.0.x264-GRP".to_string(),
+            },
+        );
+        test_grid.insert(
+            "Blade.Runner.2049.2017.1080p.BluRay.REMUX.AVC.DTS-HD.MA.7.1-FGT",
+            MediaInfo {
+                title: "Blade Runner 2049".to_string(),
+                season: 0,
+                seasons: Vec::new(),
+                episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
+                year: 2017,
+                media_type: MediaType::Movie,
+                quality: Some(quality::Quality::Q1080),
+                release_type: Some(release_type::ReleaseType::Remux),
+                video_codec: Some(video_codec::VideoCodec::H264),
+                audio_codec: Some(audio::AudioCodec::DTSHD),
+                audio_channels: Some(audio::AudioChannels::CHAN71),
+                release_group: "FGT".to_string(),
+                container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: Some(audio::AudioProfile::MA),
+                audio_tracks: vec![audio::AudioTrack {
+                    codec: Some(audio::AudioCodec::DTSHD),
+                    profile: Some(audio::AudioProfile::MA),
+                    channels: Some(audio::AudioChannels::CHAN71),
+                    language: None,
+                }],
+                episode_title: "".to_string(),
+                remux: true,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "Blade.Runner.2049.2017.1080p.BluRay.REMUX.AVC.DTS-HD.MA.7.1-FGT".to_string(),
+            },
+        );
+        test_grid.insert(
+            "The.Mandalorian.S01E01.1080p.DSNP.WEB-DL.DDP5.1.H.264-NTb",
+            MediaInfo {
+                title: "The Mandalorian".to_string(),
+                season: 1,
+                seasons: vec![1],
+                episode: 1,
+                episode_range: None,
+                episodes: vec![1],
+                is_pack: false,
+                year: 0,
+                media_type: MediaType::Episode,
+                quality: Some(quality::Quality::Q1080),
+                release_type: Some(release_type::ReleaseType::WEBDL),
+                video_codec: Some(video_codec::VideoCodec::H264),
+                audio_codec: Some(audio::AudioCodec::DolbyDigitalPlus),
+                audio_channels: Some(audio::AudioChannels::CHAN51),
+                release_group: "NTb".to_string(),
+                container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: Some(streaming_service::StreamingService::DisneyPlus),
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: vec![audio::AudioTrack {
+                    codec: Some(audio::AudioCodec::DolbyDigitalPlus),
+                    profile: None,
+                    channels: Some(audio::AudioChannels::CHAN51),
+                    language: None,
+                }],
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "The.Mandalorian.S01E01.1080p.DSNP.WEB-DL.DDP5.1.H.264-NTb".to_string(),
+            },
+        );
+        test_grid.insert(
+            "Ghost in the Shell / Koukaku Kidoutai (1995) 1080p BluRay x264-GRP",
+            MediaInfo {
+                title: "Ghost in the Shell".to_string(),
+                season: 0,
+                seasons: Vec::new(),
+                episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
+                year: 1995,
+                media_type: MediaType::Movie,
+                quality: Some(quality::Quality::Q1080),
+                release_type: Some(release_type::ReleaseType::BluRayRip),
+                video_codec: Some(video_codec::VideoCodec::H264),
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "GRP".to_string(),
+                container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: vec!["Koukaku Kidoutai".to_string()],
+                raw: "Ghost in the Shell / Koukaku Kidoutai (1995) 1080p BluRay x264-GRP".to_string(),
+            },
+        );
         test_grid.insert(
             "Teenage.Mutant.Ninja.Turtles.2014.HDRip.XviD.MP3-RARBG",
             MediaInfo {
                 title: "Teenage Mutant Ninja Turtles".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Movie,
                 quality: None,
-                release_type: Some(release_type::ReleaseType::HDTV),
+                release_type: Some(release_type::ReleaseType::HDRip),
                 video_codec: Some(video_codec::VideoCodec::XVID),
                 audio_codec: Some(audio::AudioCodec::MP3),
                 audio_channels: None,
                 release_group: "RARBG".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: vec![audio::AudioTrack {
+                    codec: Some(audio::AudioCodec::MP3),
+                    profile: None,
+                    channels: None,
+                    language: None,
+                }],
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Teenage.Mutant.Ninja.Turtles.2014.HDRip.XviD.MP3-RARBG".to_string(),
             },
         );
@@ -620,16 +2129,51 @@ mod tests {
             MediaInfo {
                 title: "Dawn Of The Planet of The Apes".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Movie,
                 quality: Some(quality::Quality::Q1080),
                 release_type: Some(release_type::ReleaseType::WEBDL),
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: Some(audio::AudioCodec::DolbyDigital),
-                audio_channels: Some(audio::AudioChannels::Chan51),
+                audio_channels: Some(audio::AudioChannels::CHAN51),
                 release_group: "RARBG".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: vec![audio::AudioTrack {
+                    codec: Some(audio::AudioCodec::DolbyDigital),
+                    profile: None,
+                    channels: Some(audio::AudioChannels::CHAN51),
+                    language: None,
+                }],
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Dawn.Of.The.Planet.of.The.Apes.2014.1080p.WEB-DL.DD51.H264-RARBG".to_string(),
             },
         );
@@ -638,7 +2182,11 @@ mod tests {
             MediaInfo {
                 title: "Gotham".to_string(),
                 season: 1,
+                seasons: vec![1],
                 episode: 5,
+                episode_range: None,
+                episodes: vec![5],
+                is_pack: false,
                 year: 0,
                 media_type: MediaType::Episode,
                 quality: None,
@@ -648,6 +2196,37 @@ mod tests {
                 audio_channels: None,
                 release_group: "".to_string(),
                 container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: vec![audio::AudioTrack {
+                    codec: Some(audio::AudioCodec::AAC),
+                    profile: None,
+                    channels: None,
+                    language: None,
+                }],
+                episode_title: "Viper".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Gotham.S01E05.Viper.WEB-DL.x264.AAC".to_string(),
             },
         );
@@ -656,16 +2235,51 @@ mod tests {
             MediaInfo {
                 title: "Into The Storm".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 2014,
                 media_type: MediaType::Movie,
                 quality: Some(quality::Quality::Q1080),
                 release_type: Some(release_type::ReleaseType::WEBDL),
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: Some(audio::AudioCodec::AAC),
-                audio_channels: Some(audio::AudioChannels::Stereo),
+                audio_channels: Some(audio::AudioChannels::STEREO),
                 release_group: "RARBG".to_string(),
                 container: Some(container::Container::Matroska),
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: vec![audio::AudioTrack {
+                    codec: Some(audio::AudioCodec::AAC),
+                    profile: None,
+                    channels: Some(audio::AudioChannels::STEREO),
+                    language: None,
+                }],
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Into.The.Storm.2014.1080p.WEB-DL.AAC2.0.H264-RARBG.mkv".to_string(),
             },
         );
@@ -674,7 +2288,11 @@ mod tests {
             MediaInfo {
                 title: "One Punch Man".to_string(),
                 season: 2,
+                seasons: vec![2],
                 episode: 3,
+                episode_range: None,
+                episodes: vec![3],
+                is_pack: false,
                 year: 0,
                 media_type: MediaType::Episode,
                 quality: Some(quality::Quality::Q1080),
@@ -684,6 +2302,32 @@ mod tests {
                 audio_channels: None,
                 release_group: "HorribleSubs".to_string(),
                 container: Some(container::Container::Matroska),
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "[HorribleSubs] One Punch Man S2 - 03 [1080p].mkv".to_string(),
             },
         );
@@ -692,7 +2336,11 @@ mod tests {
             MediaInfo {
                 title: "Mob Psycho 100".to_string(),
                 season: 2,
+                seasons: vec![2],
                 episode: 10,
+                episode_range: None,
+                episodes: vec![10],
+                is_pack: false,
                 year: 0,
                 media_type: MediaType::Episode,
                 quality: Some(quality::Quality::Q720),
@@ -702,6 +2350,32 @@ mod tests {
                 audio_channels: None,
                 release_group: "HorribleSubs".to_string(),
                 container: Some(container::Container::Matroska),
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "[HorribleSubs] Mob Psycho 100 S2 - 10 [720p].mkv".to_string(),
             },
         );
@@ -710,7 +2384,11 @@ mod tests {
             MediaInfo {
                 title: "Doctor Who".to_string(),
                 season: 6,
+                seasons: vec![6],
                 episode: 1,
+                episode_range: None,
+                episodes: vec![1],
+                is_pack: false,
                 year: 2005,
                 media_type: MediaType::Episode,
                 quality: None,
@@ -720,6 +2398,32 @@ mod tests {
                 audio_channels: None,
                 release_group: "".to_string(),
                 container: Some(container::Container::AVI),
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "Series/Doctor Who (2005)/Season 06/Doctor Who (2005) - E01.avi".to_string(),
             },
         );
@@ -728,7 +2432,11 @@ mod tests {
             MediaInfo {
                 title: "Django Unchained".to_string(),
                 season: 0,
+                seasons: Vec::new(),
                 episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
                 year: 0,
                 media_type: MediaType::Movie,
                 quality: None,
@@ -736,8 +2444,34 @@ mod tests {
                 video_codec: Some(video_codec::VideoCodec::XVID),
                 audio_codec: None,
                 audio_channels: None,
-                release_group: "cd1".to_string(),
+                release_group: "".to_string(),
                 container: Some(container::Container::AVI),
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 1,
+                alternative_titles: Vec::new(),
                 raw: "/var/lib/flemzerd/library/movies/Django Unchained/sparks-django-xvid.cd1.avi".to_string(),
             },
         );
@@ -746,7 +2480,11 @@ mod tests {
             MediaInfo {
                 title: "Rick and Morty".to_string(),
                 season: 3,
+                seasons: vec![3],
                 episode: 10,
+                episode_range: None,
+                episodes: vec![10],
+                is_pack: false,
                 year: 0,
                 media_type: MediaType::Episode,
                 quality: Some(quality::Quality::Q720),
@@ -754,12 +2492,684 @@ mod tests {
                 video_codec: Some(video_codec::VideoCodec::H264),
                 audio_codec: None,
                 audio_channels: None,
-                release_group: "BATV[eztv]".to_string(),
+                release_group: "BATV".to_string(),
                 container: Some(container::Container::Matroska),
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: Some("eztv".to_string()),
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
                 raw: "/var/lib/flemzerd/library/shows/rick_and_morty/season_3/s03e10/Rick and Morty S03E10 720p HDTV x264-BATV/Rick.and.Morty.S03E10.720p.HDTV.x264-BATV[eztv].mkv".to_string(),
             },
         );
 
+        test_grid.insert(
+            "The Tonight Show 2019-10-21 720p HDTV x264-SORNY",
+            MediaInfo {
+                title: "The Tonight Show".to_string(),
+                season: 0,
+                seasons: Vec::new(),
+                episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
+                year: 2019,
+                media_type: MediaType::DailyEpisode,
+                quality: Some(quality::Quality::Q720),
+                release_type: Some(release_type::ReleaseType::HDTV),
+                video_codec: Some(video_codec::VideoCodec::H264),
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "SORNY".to_string(),
+                container: None,
+                air_date: Some(air_date::AirDate {
+                    year: 2019,
+                    month: 10,
+                    day: 21,
+                }),
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "The Tonight Show 2019-10-21 720p HDTV x264-SORNY".to_string(),
+            },
+        );
+
+        test_grid.insert(
+            "[SubsPlease] One Piece - 1071 [1080p].mkv",
+            MediaInfo {
+                title: "One Piece".to_string(),
+                season: 0,
+                seasons: Vec::new(),
+                episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
+                year: 0,
+                media_type: MediaType::Episode,
+                quality: Some(quality::Quality::Q1080),
+                release_type: None,
+                video_codec: None,
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "SubsPlease".to_string(),
+                container: Some(container::Container::Matroska),
+                air_date: None,
+                absolute_episode: 1071,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "[SubsPlease] One Piece - 1071 [1080p].mkv".to_string(),
+            },
+        );
+
+        test_grid.insert(
+            "[SubsPlease] One Piece - 1072 [1080p][A1B2C3D4].mkv",
+            MediaInfo {
+                title: "One Piece".to_string(),
+                season: 0,
+                seasons: Vec::new(),
+                episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
+                year: 0,
+                media_type: MediaType::Episode,
+                quality: Some(quality::Quality::Q1080),
+                release_type: None,
+                video_codec: None,
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "SubsPlease".to_string(),
+                container: Some(container::Container::Matroska),
+                air_date: None,
+                absolute_episode: 1072,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: Some("A1B2C3D4".to_string()),
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "[SubsPlease] One Piece - 1072 [1080p][A1B2C3D4].mkv".to_string(),
+            },
+        );
+
+        test_grid.insert(
+            "VOSTFR",
+            MediaInfo {
+                title: "VOSTFR".to_string(),
+                season: 0,
+                seasons: Vec::new(),
+                episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
+                year: 0,
+                media_type: MediaType::Movie,
+                quality: None,
+                release_type: None,
+                video_codec: None,
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "".to_string(),
+                container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: Some(language::AudioVersion::Original),
+                subtitles: Some(subtitle::Subtitles {
+                    languages: vec![language::Language::French],
+                    multi: false,
+                    hardcoded: false,
+                    soft: false,
+                }),
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "VOSTFR".to_string(),
+            },
+        );
+
+        test_grid.insert(
+            "Film.2010.DVD1.XviD",
+            MediaInfo {
+                title: "Film".to_string(),
+                season: 0,
+                seasons: Vec::new(),
+                episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
+                year: 2010,
+                media_type: MediaType::Movie,
+                quality: None,
+                release_type: Some(release_type::ReleaseType::DVDRip),
+                video_codec: Some(video_codec::VideoCodec::XVID),
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "".to_string(),
+                container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: true,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 1,
+                alternative_titles: Vec::new(),
+                raw: "Film.2010.DVD1.XviD".to_string(),
+            },
+        );
+
+        test_grid.insert(
+            "Inception (2010) {edition-Extended} 1080p",
+            MediaInfo {
+                title: "Inception".to_string(),
+                season: 0,
+                seasons: Vec::new(),
+                episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
+                year: 2010,
+                media_type: MediaType::Movie,
+                quality: Some(quality::Quality::Q1080),
+                release_type: None,
+                video_codec: None,
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "".to_string(),
+                container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: Some(edition::Edition::Extended),
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "Inception (2010) {edition-Extended} 1080p".to_string(),
+            },
+        );
+
+        test_grid.insert(
+            "Inception (2010) {edition-Theatrical} 1080p",
+            MediaInfo {
+                title: "Inception".to_string(),
+                season: 0,
+                seasons: Vec::new(),
+                episode: 0,
+                episode_range: None,
+                episodes: Vec::new(),
+                is_pack: false,
+                year: 2010,
+                media_type: MediaType::Movie,
+                quality: Some(quality::Quality::Q1080),
+                release_type: None,
+                video_codec: None,
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "".to_string(),
+                container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: Some(edition::Edition::Theatrical),
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "Inception (2010) {edition-Theatrical} 1080p".to_string(),
+            },
+        );
+
+        test_grid.insert(
+            "Show.S01E01.Title.H.264-GRP",
+            MediaInfo {
+                title: "Show".to_string(),
+                season: 1,
+                seasons: vec![1],
+                episode: 1,
+                episode_range: None,
+                episodes: vec![1],
+                is_pack: false,
+                year: 0,
+                media_type: MediaType::Episode,
+                quality: None,
+                release_type: None,
+                video_codec: Some(video_codec::VideoCodec::H264),
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "GRP".to_string(),
+                container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "Title".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "Show.S01E01.Title.H.264-GRP".to_string(),
+            },
+        );
+
+        test_grid.insert(
+            "Show.S01E01.Title.DV.2160p",
+            MediaInfo {
+                title: "Show".to_string(),
+                season: 1,
+                seasons: vec![1],
+                episode: 1,
+                episode_range: None,
+                episodes: vec![1],
+                is_pack: false,
+                year: 0,
+                media_type: MediaType::Episode,
+                quality: Some(quality::Quality::Q2160),
+                release_type: None,
+                video_codec: None,
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "".to_string(),
+                container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: vec![video_codec::HdrFormat::DolbyVision],
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "Title".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "Show.S01E01.Title.DV.2160p".to_string(),
+            },
+        );
+
+        test_grid.insert(
+            "Show.S01E01.Title.3D.HSBS",
+            MediaInfo {
+                title: "Show".to_string(),
+                season: 1,
+                seasons: vec![1],
+                episode: 1,
+                episode_range: None,
+                episodes: vec![1],
+                is_pack: false,
+                year: 0,
+                media_type: MediaType::Episode,
+                quality: None,
+                release_type: None,
+                video_codec: None,
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "".to_string(),
+                container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "Title".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: Some(stereo_3d::Stereo3D::HalfSideBySide),
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "Show.S01E01.Title.3D.HSBS".to_string(),
+            },
+        );
+
+        test_grid.insert(
+            "Show.S01E01.Title.CD1.avi",
+            MediaInfo {
+                title: "Show".to_string(),
+                season: 1,
+                seasons: vec![1],
+                episode: 1,
+                episode_range: None,
+                episodes: vec![1],
+                is_pack: false,
+                year: 0,
+                media_type: MediaType::Episode,
+                quality: None,
+                release_type: None,
+                video_codec: None,
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "".to_string(),
+                container: Some(container::Container::AVI),
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "Title".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 1,
+                alternative_titles: Vec::new(),
+                raw: "Show.S01E01.Title.CD1.avi".to_string(),
+            },
+        );
+
+        test_grid.insert(
+            "Show.S01E01-03.1080p",
+            MediaInfo {
+                title: "Show".to_string(),
+                season: 1,
+                seasons: vec![1],
+                episode: 1,
+                episode_range: Some(episode::EpisodeRange { start: 1, end: 3 }),
+                episodes: vec![1, 2, 3],
+                is_pack: false,
+                year: 0,
+                media_type: MediaType::Episode,
+                quality: Some(quality::Quality::Q1080),
+                release_type: None,
+                video_codec: None,
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "".to_string(),
+                container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "Show.S01E01-03.1080p".to_string(),
+            },
+        );
+
+        test_grid.insert(
+            "Show 1x01-1x02 HDTV",
+            MediaInfo {
+                title: "Show".to_string(),
+                season: 1,
+                seasons: vec![1],
+                episode: 1,
+                episode_range: Some(episode::EpisodeRange { start: 1, end: 2 }),
+                episodes: vec![1, 2],
+                is_pack: false,
+                year: 0,
+                media_type: MediaType::Episode,
+                quality: None,
+                release_type: Some(release_type::ReleaseType::HDTV),
+                video_codec: None,
+                audio_codec: None,
+                audio_channels: None,
+                release_group: "".to_string(),
+                container: None,
+                air_date: None,
+                absolute_episode: 0,
+                languages: Vec::new(),
+                audio_version: None,
+                subtitles: None,
+                edition: None,
+                bit_depth: 0,
+                hdr_formats: Vec::new(),
+                streaming_service: None,
+                revision: None,
+                is_encode: false,
+                audio_profile: None,
+                audio_tracks: Vec::new(),
+                episode_title: "".to_string(),
+                remux: false,
+                disc_type: None,
+                frame_rate: None,
+                website: None,
+                indexer_tag: None,
+                stereo_3d: None,
+                special_kind: None,
+                country: None,
+                crc32: None,
+                part: 0,
+                disc: 0,
+                alternative_titles: Vec::new(),
+                raw: "Show 1x01-1x02 HDTV".to_string(),
+            },
+        );
+
         for (key, val) in test_grid.iter() {
             println!("Test item: {}", key);
             let info = parse(key, None);
@@ -788,7 +3198,10 @@ mod tests {
 
         for (key, val) in test_grid.iter() {
             println!("Test item: {}", key);
-            let options = configuration::CliOptions { media_type: val.0 };
+            let options = configuration::CliOptions {
+                media_type: val.0,
+                date_order: None,
+            };
             let info = parse(key, Some(options));
 
             assert_eq!(val.1, info.media_type);
@@ -20,12 +20,20 @@ fn main() {
             .short("t")
             .long("type")
             .help("Type of the media to detect. Possible values are 'movie, episode'. If this option is not passed, the media type will be detected automatically"))
+        .arg(Arg::with_name("DATE_ORDER")
+            .required(false)
+            .takes_value(true)
+            .short("d")
+            .long("date-order")
+            .possible_values(&["dmy", "mdy"])
+            .help("Order of day and month in ambiguous air dates such as 05.01.2019. Possible values are 'dmy, mdy'. Defaults to 'dmy'"))
         .get_matches();
 
     let name = matches.value_of("NAME").unwrap();
 
     let options: vidocq::configuration::CliOptions = vidocq::configuration::CliOptions {
         media_type: matches.value_of("TYPE"),
+        date_order: matches.value_of("DATE_ORDER"),
     };
     let info = vidocq::parse(name, Some(options));
     let j = serde_json::to_string(&info).unwrap();
@@ -0,0 +1,120 @@
+use regex::Regex;
+
+use super::utils;
+use super::year;
+
+lazy_static! {
+    //"DVD5" and "DVD9" are disc formats, not disc numbers
+    static ref RE_DISC: Regex = utils::token_regex(
+        r"(?i:(?:cd|dis[ck])[\s._-]?(?P<disc>\d{1,2})|dvd(?P<dvd>[1-46-8]))"
+    );
+    static ref RE_PART: Regex = utils::token_regex(r"(?i:pt[\s._-]?(?P<part>\d{1,2}))");
+    //"Part 2" is often part of a sequel title ("Kill Bill Part 2"), see is_file_part
+    static ref RE_TITLE_PART: Regex = utils::token_regex(r"(?i:part[\s._-]?(?P<part>\d{1,2}))");
+}
+
+//"Part N" only designates a file part when found after the year, where the title is over:
+//"Kill.Bill.Part.2.2004" is a title, "Movie.2004.Part.2.avi" is the second file of a movie
+fn is_file_part(name: &str, start: usize) -> bool {
+    let year_str: String = year::parse(name).to_string();
+    if year_str == "0" {
+        return false;
+    }
+
+    name.rfind(&year_str).is_some_and(|offset| start > offset)
+}
+
+fn find_part(name: &str) -> Option<(usize, &Regex)> {
+    if let Some(m) = RE_PART.find(name) {
+        return Some((m.start(), &*RE_PART));
+    }
+
+    RE_TITLE_PART
+        .find_iter(name)
+        .find(|m| is_file_part(name, m.start()))
+        .map(|m| (m.start(), &*RE_TITLE_PART))
+}
+
+//Start offset of the first part or disc marker in name
+pub fn position(name: &str) -> Option<usize> {
+    let disc = RE_DISC.find(name).map(|m| m.start());
+    let part = find_part(name).map(|(start, _)| start);
+
+    disc.into_iter().chain(part).min()
+}
+
+//Parse file part and disc numbers of multi-file releases: "cd1", "Disc 2", "DVD1", "pt3", "Part.2"
+pub fn parse(name: String) -> (i32, i32, String) {
+    let mut stripped = name;
+
+    let mut disc: i32 = 0;
+    let mut is_dvd = false;
+    if let Some(captures) = RE_DISC.captures(&stripped) {
+        disc = captures
+            .name("disc")
+            .or_else(|| captures.name("dvd"))
+            .map_or(0, |m| m.as_str().parse::<i32>().unwrap_or(0));
+        is_dvd = captures.name("dvd").is_some();
+    }
+    //"DVD1" is left in place: it is also the DVD source read by release_type::parse
+    if disc != 0 && !is_dvd {
+        stripped = RE_DISC.replace(&stripped, "${after}").to_string();
+    }
+
+    let mut part: i32 = 0;
+    if let Some((start, reg)) = find_part(&stripped) {
+        let captures = reg.captures(&stripped[start..]).unwrap();
+        part = captures["part"].parse::<i32>().unwrap_or(0);
+        stripped = format!(
+            "{}{}",
+            &stripped[..start],
+            reg.replace(&stripped[start..], "${after}")
+        );
+    }
+
+    (part, disc, stripped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parse_part() {
+        let mut test_grid: HashMap<&str, (i32, i32)> = HashMap::new();
+        test_grid.insert("sparks-django-xvid.cd1.avi", (0, 1));
+        test_grid.insert("Movie.2004.CD2.XviD-GRP.avi", (0, 2));
+        test_grid.insert("Movie 2004 Disc 2 1080p", (0, 2));
+        test_grid.insert("Movie.2004.Disk3.DVDRip", (0, 3));
+        test_grid.insert("Movie.2004.DVD1.XviD", (0, 1));
+        test_grid.insert("Movie.2004.DVD9.Remux", (0, 0));
+        test_grid.insert("Movie.2004.pt3.XviD-GRP.avi", (3, 0));
+        test_grid.insert("Movie.pt1.XviD-GRP.avi", (1, 0));
+        test_grid.insert("Movie.2004.Part.2.XviD-GRP.avi", (2, 0));
+        test_grid.insert("Kill.Bill.Part.2.2004.1080p.BluRay", (0, 0));
+        test_grid.insert("Kill Bill Part 2", (0, 0));
+        test_grid.insert("Harry.Potter.and.the.Deathly.Hallows.Part.1.2010.CD2", (0, 2));
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let (part, disc, _) = parse(key.to_string());
+
+            assert_eq!(val, (part, disc));
+        }
+    }
+
+    #[test]
+    fn test_strip_part() {
+        let mut test_grid: HashMap<&str, &str> = HashMap::new();
+        test_grid.insert("sparks-django-xvid.cd1.avi", "sparks-django-xvid.avi");
+        test_grid.insert("Movie.2004.Part.2.XviD-GRP", "Movie.2004.XviD-GRP");
+        test_grid.insert("Kill.Bill.Part.2.2004", "Kill.Bill.Part.2.2004");
+        test_grid.insert("Movie.2010.DVD1.XviD", "Movie.2010.DVD1.XviD");
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            assert_eq!(val, parse(key.to_string()).2);
+        }
+    }
+}
@@ -18,7 +18,7 @@ pub fn parse(name: &str) -> (String, String) {
         name.rfind(&year_str).unwrap_or(0)
     } else {
         0
-    } as usize;
+    };
 
     offsets.push(year_offset);
 
@@ -43,6 +43,14 @@ pub fn parse(name: &str) -> (String, String) {
             .captures(name)
             .map_or(0, |m| m.get(0).map_or(0, |c| c.start())),
     );
+    //Multi-episode markers and season ranges contain a dash that would otherwise be read as a
+    //release group separator
+    offsets.push(episode::multi_episode_end(name).unwrap_or(0));
+    offsets.push(episode::RE_SEASON_RANGE.find(name).map_or(0, |m| m.end()));
+    //Same for the dash preceding anime absolute episode numbers
+    if episode::parse_absolute(name) != 0 {
+        offsets.push(episode::RE_ABSOLUTE_EPISODE.find(name).map_or(0, |m| m.end()));
+    }
 
     let max_offset: usize = offsets.into_iter().filter(|x| *x > 0).max().unwrap_or(0);
 
@@ -54,7 +62,7 @@ pub fn parse(name: &str) -> (String, String) {
     }
 
     let mut group: String = String::from("");
-    for capture in RE_RELEASE_GROUP.captures_iter(&work_str) {
+    for capture in RE_RELEASE_GROUP.captures_iter(work_str) {
         group = capture["group"].to_string();
     }
 
@@ -62,23 +70,23 @@ pub fn parse(name: &str) -> (String, String) {
     group = group.trim_matches('.').to_string();
 
     let mut group_at_beginning: String = String::from("");
-    for capture in RE_RELEASE_GROUP_BEGINNING.captures_iter(&name) {
+    for capture in RE_RELEASE_GROUP_BEGINNING.captures_iter(name) {
         group_at_beginning = capture["group"].to_string();
     }
 
     group_at_beginning = group_at_beginning.trim().to_string();
     group_at_beginning = group_at_beginning.trim_matches('.').to_string();
 
-    if group_at_beginning != "" {
+    if !group_at_beginning.is_empty() {
         return (
             group_at_beginning,
             RE_RELEASE_GROUP_BEGINNING
-                .replace_all(&name, "")
+                .replace_all(name, "")
                 .to_string(),
         );
     }
 
-    (group, RE_RELEASE_GROUP.replace_all(&name, "").to_string())
+    (group, RE_RELEASE_GROUP.replace_all(name, "").to_string())
 }
 
 #[cfg(test)]
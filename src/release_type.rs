@@ -1,19 +1,34 @@
 extern crate serde;
 
+use super::streaming_service;
+use super::streaming_service::StreamingService;
 use super::utils;
 
 use regex::Regex;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum ReleaseType {
     Cam,
     Telesync,
     Telecine,
     Screener,
+    Workprint,
+    PPV,
+    VHSRip,
     DVDRip,
+    HDDVD,
+    SDTV,
+    SATRip,
     HDTV,
+    HDRip,
+    WEB,
+    WEBRip,
     WEBDL,
+    BDRip,
     BluRayRip,
+    UHDBluRay,
+    Remux,
 }
 
 impl serde::Serialize for ReleaseType {
@@ -32,114 +47,161 @@ impl serde::Serialize for ReleaseType {
             ReleaseType::Screener => {
                 serializer.serialize_unit_variant("ReleaseType", 0, "screener")
             }
+            ReleaseType::Workprint => {
+                serializer.serialize_unit_variant("ReleaseType", 0, "workprint")
+            }
+            ReleaseType::PPV => serializer.serialize_unit_variant("ReleaseType", 0, "ppv"),
+            ReleaseType::VHSRip => serializer.serialize_unit_variant("ReleaseType", 0, "vhsrip"),
             ReleaseType::DVDRip => serializer.serialize_unit_variant("ReleaseType", 0, "dvdrip"),
+            ReleaseType::HDDVD => serializer.serialize_unit_variant("ReleaseType", 0, "hddvd"),
+            ReleaseType::SDTV => serializer.serialize_unit_variant("ReleaseType", 0, "sdtv"),
+            ReleaseType::SATRip => serializer.serialize_unit_variant("ReleaseType", 0, "satrip"),
             ReleaseType::HDTV => serializer.serialize_unit_variant("ReleaseType", 0, "hdtv"),
+            ReleaseType::HDRip => serializer.serialize_unit_variant("ReleaseType", 0, "hdrip"),
+            ReleaseType::WEB => serializer.serialize_unit_variant("ReleaseType", 0, "web"),
+            ReleaseType::WEBRip => serializer.serialize_unit_variant("ReleaseType", 0, "webrip"),
             ReleaseType::WEBDL => serializer.serialize_unit_variant("ReleaseType", 0, "webdl"),
+            ReleaseType::BDRip => serializer.serialize_unit_variant("ReleaseType", 0, "bdrip"),
             ReleaseType::BluRayRip => {
                 serializer.serialize_unit_variant("ReleaseType", 0, "blurayrip")
             }
+            ReleaseType::UHDBluRay => {
+                serializer.serialize_unit_variant("ReleaseType", 0, "uhd_bluray")
+            }
+            ReleaseType::Remux => serializer.serialize_unit_variant("ReleaseType", 0, "remux"),
         }
     }
 }
 
-pub fn parse(name: String) -> (Option<ReleaseType>, String) {
-    lazy_static! {
-        static ref RE_CAM: Vec<Regex> = vec![Regex::new(r"(?i)(HD)?CAM(RIP)?").unwrap(),];
-        static ref RE_TELESYNC: Vec<Regex> = vec![
-            Regex::new(r"(HD)?TS").unwrap(),
-            Regex::new(r"(?i)TELESYNC").unwrap(),
-            Regex::new(r"(?i)PDVD").unwrap(),
-            Regex::new(r"(?i)PreDVDRip").unwrap(),
-        ];
-        static ref RE_TELECINE: Vec<Regex> = vec![
-            Regex::new(r"(?i)(HD)?TC").unwrap(),
-            Regex::new(r"(?i)TELECINE").unwrap(),
-        ];
-        static ref RE_SCREENER: Vec<Regex> = vec![
-            Regex::new(r"(?i)(DVD|BD)?SCR(EENER)?").unwrap(),
-            Regex::new(r"(?i)DDC").unwrap(),
-        ];
-        static ref RE_DVDRIP: Vec<Regex> = vec![
-            Regex::new(r"(?i)DVDR(IP)?").unwrap(),
-            Regex::new(r"(?i)DVDMux").unwrap(),
-            Regex::new(r"(?i)DVD-?(Full|\d{1,2})").unwrap(),
-        ];
-        static ref RE_HDTV: Vec<Regex> = vec![
-            Regex::new(r"(HD|PD)TV").unwrap(),
-            Regex::new(r"(?i)(HD|DS|SAT|DTH|DVB|TV|HDTV)Rip").unwrap(),
-            Regex::new(r"DSR").unwrap(),
-        ];
-        static ref RE_WEBDL: Vec<Regex> = vec![
-            Regex::new(r"(?i)WEB[-\s]?DL").unwrap(),
-            Regex::new(r"(?i)WEB[-\s]?Rip").unwrap(),
-            Regex::new(r"(?i)WEB[-\s]?Cap").unwrap(),
-        ];
-        static ref RE_BLURAY: Vec<Regex> = vec![
-            Regex::new(r"(?i)Blu[-\s]?Ray").unwrap(),
-            Regex::new(r"(?i)B[RD](Rip|MV|R|25|50|5|9)").unwrap(),
-        ];
-    }
-
-    let mut matched_release_type: Option<ReleaseType> = None;
-    let original_name = name.clone();
-
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_BLURAY.to_vec());
-    if matched {
-        matched_release_type = Some(ReleaseType::BluRayRip);
-
-        return (matched_release_type, stripped_name);
-    }
-
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_DVDRIP.to_vec());
-    if matched {
-        matched_release_type = Some(ReleaseType::DVDRip);
-
-        return (matched_release_type, stripped_name);
-    }
-
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_WEBDL.to_vec());
-    if matched {
-        matched_release_type = Some(ReleaseType::WEBDL);
-
-        return (matched_release_type, stripped_name);
-    }
-
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_HDTV.to_vec());
-    if matched {
-        matched_release_type = Some(ReleaseType::HDTV);
-
-        return (matched_release_type, stripped_name);
-    }
-
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_SCREENER.to_vec());
-    if matched {
-        matched_release_type = Some(ReleaseType::Screener);
-
-        return (matched_release_type, stripped_name);
-    }
-
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_TELECINE.to_vec());
-    if matched {
-        matched_release_type = Some(ReleaseType::Telecine);
+lazy_static! {
+    static ref RE_CAM: Vec<Regex> = vec![Regex::new(r"(?i)(HD)?CAM(RIP)?").unwrap(),];
+    static ref RE_TELESYNC: Vec<Regex> = vec![
+        utils::token_regex(r"(?:HD)?TS"),
+        Regex::new(r"(?i)TELESYNC").unwrap(),
+        Regex::new(r"(?i)PDVD").unwrap(),
+        Regex::new(r"(?i)PreDVDRip").unwrap(),
+    ];
+    static ref RE_TELECINE: Vec<Regex> = vec![
+        Regex::new(r"(?i)(HD)?TC").unwrap(),
+        Regex::new(r"(?i)TELECINE").unwrap(),
+    ];
+    static ref RE_SCREENER: Vec<Regex> = vec![
+        Regex::new(r"(?i)(DVD|BD)?SCR(EENER)?").unwrap(),
+        Regex::new(r"(?i)DDC").unwrap(),
+    ];
+    static ref RE_WORKPRINT: Vec<Regex> = vec![
+        Regex::new(r"(?i)WORKPRINT").unwrap(),
+        utils::token_regex(r"WP"),
+    ];
+    static ref RE_PPV: Vec<Regex> = vec![utils::token_regex(r"(?i:PPV(?:Rip)?)")];
+    static ref RE_VHSRIP: Vec<Regex> = vec![
+        Regex::new(r"(?i)VHSRip").unwrap(),
+        utils::token_regex(r"VHS"),
+    ];
+    static ref RE_DVDRIP: Vec<Regex> = vec![
+        Regex::new(r"(?i)DVDR(IP)?").unwrap(),
+        Regex::new(r"(?i)DVDMux").unwrap(),
+        Regex::new(r"(?i)DVD-?(Full|\d{1,2})").unwrap(),
+    ];
+    static ref RE_HDDVD: Vec<Regex> = vec![Regex::new(r"(?i)HD[-\s]?DVD(Rip)?").unwrap()];
+    static ref RE_HDTV: Vec<Regex> = vec![
+        Regex::new(r"(?i)HDTVRip").unwrap(),
+        Regex::new(r"HDTV").unwrap(),
+    ];
+    static ref RE_SDTV: Vec<Regex> = vec![
+        Regex::new(r"(SD|PD)TV").unwrap(),
+        Regex::new(r"(?i)TVRip").unwrap(),
+    ];
+    static ref RE_SATRIP: Vec<Regex> = vec![
+        Regex::new(r"(?i)(DS|SAT|DTH|DVB)Rip").unwrap(),
+        Regex::new(r"DSR").unwrap(),
+    ];
+    static ref RE_HDRIP: Vec<Regex> = vec![Regex::new(r"(?i)HDRip").unwrap()];
+    //"WEB-DLRip" is a rip of a WEB-DL
+    static ref RE_WEBRIP: Vec<Regex> = vec![
+        Regex::new(r"(?i)WEB[-\s.]?DL[-\s.]?Rip").unwrap(),
+        Regex::new(r"(?i)WEB[-\s.]?Rip").unwrap(),
+        Regex::new(r"(?i)WEB[-\s.]?Cap").unwrap(),
+    ];
+    static ref RE_WEBDL: Vec<Regex> = vec![Regex::new(r"(?i)WEB[-\s.]?DL").unwrap()];
+    //Untagged web releases. Case sensitive: "Charlottes.Web.2006" is a title
+    static ref RE_WEB: Vec<Regex> = vec![utils::token_regex(r"(?P<web>WEB)")];
+    static ref RE_REMUX: Vec<Regex> = vec![Regex::new(
+        r"(?i)(?:UHD[\s._-]?)?(?:Blu[-\s]?Ray[\s._-]?|BD[\s._-]?)?Remux"
+    )
+    .unwrap()];
+    static ref RE_UHD_BLURAY: Vec<Regex> = vec![
+        Regex::new(r"(?i)UHD[\s._-]?Blu[-\s]?Ray").unwrap(),
+        Regex::new(r"(?i)Blu[-\s]?Ray[\s._-]?UHD").unwrap(),
+        Regex::new(r"(?i)UHD[\s._-]?BD(Rip)?").unwrap(),
+    ];
+    static ref RE_BDRIP: Vec<Regex> = vec![Regex::new(r"(?i)B[RD]Rip").unwrap()];
+    static ref RE_BLURAY: Vec<Regex> = vec![
+        Regex::new(r"(?i)Blu[-\s]?Ray(Rip)?").unwrap(),
+        Regex::new(r"(?i)B[RD](MV|R|25|50|66|100|5|9)").unwrap(),
+    ];
+}
 
-        return (matched_release_type, stripped_name);
+pub fn parse(name: String) -> (Option<ReleaseType>, Option<StreamingService>, String) {
+    //Disc sources first, most specific first: "UHD.BluRay.Remux" is a remux
+    let disc_sources: Vec<(&Vec<Regex>, ReleaseType)> = vec![
+        (&RE_REMUX, ReleaseType::Remux),
+        (&RE_UHD_BLURAY, ReleaseType::UHDBluRay),
+        (&RE_BDRIP, ReleaseType::BDRip),
+        (&RE_BLURAY, ReleaseType::BluRayRip),
+        (&RE_HDDVD, ReleaseType::HDDVD),
+        (&RE_DVDRIP, ReleaseType::DVDRip),
+    ];
+    for (regex_table, release_type) in disc_sources {
+        let (matched, stripped_name) = utils::find_and_strip_token(&name, regex_table.to_vec());
+        if matched {
+            return (Some(release_type), None, stripped_name);
+        }
     }
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_TELESYNC.to_vec());
-    if matched {
-        matched_release_type = Some(ReleaseType::Telesync);
-
-        return (matched_release_type, stripped_name);
+    //Streaming services are only looked for next to the WEB source marker
+    let web_sources: Vec<(&Vec<Regex>, ReleaseType)> = vec![
+        (&RE_WEBRIP, ReleaseType::WEBRip),
+        (&RE_WEBDL, ReleaseType::WEBDL),
+        (&RE_WEB, ReleaseType::WEB),
+    ];
+    for (regex_table, release_type) in web_sources {
+        if let Some(web) = regex_table
+            .iter()
+            .filter_map(|reg| reg.captures(&name))
+            .filter_map(|captures| captures.name("web").or_else(|| captures.get(0)))
+            .next()
+        {
+            let (service, name_without_service) =
+                streaming_service::parse(&name, web.start(), web.end());
+            let (_, stripped_name) =
+                utils::find_and_strip_token(&name_without_service, regex_table.to_vec());
+
+            return (Some(release_type), service, stripped_name);
+        }
     }
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_CAM.to_vec());
-    if matched {
-        matched_release_type = Some(ReleaseType::Cam);
-
-        return (matched_release_type, stripped_name);
+    let other_sources: Vec<(&Vec<Regex>, ReleaseType)> = vec![
+        (&RE_HDRIP, ReleaseType::HDRip),
+        (&RE_HDTV, ReleaseType::HDTV),
+        (&RE_SDTV, ReleaseType::SDTV),
+        (&RE_SATRIP, ReleaseType::SATRip),
+        (&RE_VHSRIP, ReleaseType::VHSRip),
+        (&RE_PPV, ReleaseType::PPV),
+        (&RE_WORKPRINT, ReleaseType::Workprint),
+        (&RE_SCREENER, ReleaseType::Screener),
+        (&RE_TELECINE, ReleaseType::Telecine),
+        (&RE_TELESYNC, ReleaseType::Telesync),
+        (&RE_CAM, ReleaseType::Cam),
+    ];
+    for (regex_table, release_type) in other_sources {
+        let (matched, stripped_name) = utils::find_and_strip_token(&name, regex_table.to_vec());
+        if matched {
+            return (Some(release_type), None, stripped_name);
+        }
     }
 
-    (matched_release_type, original_name)
+    (None, None, name)
 }
 
 #[cfg(test)]
@@ -182,40 +244,61 @@ mod tests {
         test_grid.insert("DVD-9", ReleaseType::DVDRip);
 
         test_grid.insert("HDTV", ReleaseType::HDTV);
-        test_grid.insert("DSR", ReleaseType::HDTV);
-        test_grid.insert("DSRip", ReleaseType::HDTV);
-        test_grid.insert("SATRip", ReleaseType::HDTV);
-        test_grid.insert("DTHRip", ReleaseType::HDTV);
-        test_grid.insert("DVBRip", ReleaseType::HDTV);
+        test_grid.insert("DSR", ReleaseType::SATRip);
+        test_grid.insert("DSRip", ReleaseType::SATRip);
+        test_grid.insert("SATRip", ReleaseType::SATRip);
+        test_grid.insert("DTHRip", ReleaseType::SATRip);
+        test_grid.insert("DVBRip", ReleaseType::SATRip);
         test_grid.insert("HDTV", ReleaseType::HDTV);
-        test_grid.insert("PDTV", ReleaseType::HDTV);
-        test_grid.insert("TVRip", ReleaseType::HDTV);
+        test_grid.insert("PDTV", ReleaseType::SDTV);
+        test_grid.insert("SDTV", ReleaseType::SDTV);
+        test_grid.insert("TVRip", ReleaseType::SDTV);
         test_grid.insert("HDTVRip", ReleaseType::HDTV);
-        test_grid.insert("HDRip", ReleaseType::HDTV);
+        test_grid.insert("HDRip", ReleaseType::HDRip);
 
         test_grid.insert("WEBDL", ReleaseType::WEBDL);
         test_grid.insert("WEB DL", ReleaseType::WEBDL);
         test_grid.insert("WEB-DL", ReleaseType::WEBDL);
-        test_grid.insert("WEB-DLRip", ReleaseType::WEBDL);
-        test_grid.insert("WEBRip (P2P)", ReleaseType::WEBDL);
-        test_grid.insert("WEB Rip (P2P)", ReleaseType::WEBDL);
-        test_grid.insert("WEB-Rip (P2P)", ReleaseType::WEBDL);
-        test_grid.insert("WEB-Cap", ReleaseType::WEBDL);
-        test_grid.insert("WEBCAP", ReleaseType::WEBDL);
-        test_grid.insert("WEB Cap", ReleaseType::WEBDL);
+        test_grid.insert("Movie.2019.1080p.WEB.DL", ReleaseType::WEBDL);
+        test_grid.insert("WEB-DLRip", ReleaseType::WEBRip);
+        test_grid.insert("WEBRip (P2P)", ReleaseType::WEBRip);
+        test_grid.insert("WEB Rip (P2P)", ReleaseType::WEBRip);
+        test_grid.insert("WEB-Rip (P2P)", ReleaseType::WEBRip);
+        test_grid.insert("WEB-Cap", ReleaseType::WEBRip);
+        test_grid.insert("WEBCAP", ReleaseType::WEBRip);
+        test_grid.insert("WEB Cap", ReleaseType::WEBRip);
+        test_grid.insert("Show.S01E01.1080p.WEB.H264", ReleaseType::WEB);
 
         test_grid.insert("BluRayRip", ReleaseType::BluRayRip);
         test_grid.insert("Blu-Ray", ReleaseType::BluRayRip);
         test_grid.insert("BluRay", ReleaseType::BluRayRip);
         test_grid.insert("BLURAY", ReleaseType::BluRayRip);
-        test_grid.insert("BDRip", ReleaseType::BluRayRip);
-        test_grid.insert("BRRip", ReleaseType::BluRayRip);
+        test_grid.insert("BDRip", ReleaseType::BDRip);
+        test_grid.insert("BRRip", ReleaseType::BDRip);
         test_grid.insert("BDMV", ReleaseType::BluRayRip);
         test_grid.insert("BDR", ReleaseType::BluRayRip);
         test_grid.insert("BD25", ReleaseType::BluRayRip);
         test_grid.insert("BD50", ReleaseType::BluRayRip);
         test_grid.insert("BD5", ReleaseType::BluRayRip);
         test_grid.insert("BD9", ReleaseType::BluRayRip);
+        test_grid.insert("Movie.2019.2160p.BD66-GRP", ReleaseType::BluRayRip);
+        test_grid.insert("Movie.2019.2160p.BD100-GRP", ReleaseType::BluRayRip);
+
+        test_grid.insert("UHD.BluRay", ReleaseType::UHDBluRay);
+        test_grid.insert("UHD BluRay", ReleaseType::UHDBluRay);
+        test_grid.insert("UHDBD", ReleaseType::UHDBluRay);
+        test_grid.insert("BluRay.Remux", ReleaseType::Remux);
+        test_grid.insert("BDRemux", ReleaseType::Remux);
+        test_grid.insert("UHD.BluRay.REMUX", ReleaseType::Remux);
+        test_grid.insert("REMUX", ReleaseType::Remux);
+
+        test_grid.insert("HD-DVD", ReleaseType::HDDVD);
+        test_grid.insert("HDDVDRip", ReleaseType::HDDVD);
+        test_grid.insert("VHSRip", ReleaseType::VHSRip);
+        test_grid.insert("Movie.1985.VHS.XviD", ReleaseType::VHSRip);
+        test_grid.insert("WORKPRINT", ReleaseType::Workprint);
+        test_grid.insert("Movie.2019.WP.XviD", ReleaseType::Workprint);
+        test_grid.insert("UFC.245.PPV.720p", ReleaseType::PPV);
 
         for (key, val) in test_grid {
             println!("Test item: {}", key);
@@ -223,5 +306,8 @@ mod tests {
 
             assert_eq!(val, release_type);
         }
+
+        //"TS" inside "DTS-HD" is not a telesync
+        assert_eq!(None, super::parse("Show.S01E01.Title.DTS-HD.MA".to_string()).0);
     }
 }
@@ -0,0 +1,181 @@
+extern crate serde;
+
+use super::utils;
+
+use regex::Regex;
+
+#[derive(Debug, PartialEq)]
+pub enum RevisionKind {
+    Proper,
+    Repack,
+    Rerip,
+    Version,
+}
+
+impl serde::Serialize for RevisionKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match *self {
+            RevisionKind::Proper => serializer.serialize_unit_variant("RevisionKind", 0, "proper"),
+            RevisionKind::Repack => serializer.serialize_unit_variant("RevisionKind", 0, "repack"),
+            RevisionKind::Rerip => serializer.serialize_unit_variant("RevisionKind", 0, "rerip"),
+            RevisionKind::Version => {
+                serializer.serialize_unit_variant("RevisionKind", 0, "version")
+            }
+        }
+    }
+}
+
+//Original releases are version 1. Each fix bumps the version so that higher always means better
+#[derive(Serialize, Debug, PartialEq)]
+pub struct Revision {
+    pub version: i32,
+    pub kind: RevisionKind,
+    pub real: bool,
+}
+
+lazy_static! {
+    //"REAL" is only a revision marker when followed by another fix marker: "Real.Steel" is a title
+    static ref RE_REAL: Regex =
+        Regex::new(r"\bREAL[\s._-](?P<fix>PROPER|REPACK|RERIP)").unwrap();
+    static ref RE_FIX: Regex = utils::token_regex(
+        r"(?P<kind>PROPER|REPACK|RERIP|proper|repack|rerip)(?P<number>\d)?"
+    );
+    //Anime re-releases: "Title - 05v2", "S01E05v2"
+    static ref RE_VERSION: Regex = Regex::new(
+        r"(?P<episode>(?:^|[\s._\-eE])\d{1,4})v(?P<version>\d)(?P<after>[\s._\-\[\](){}]|$)"
+    )
+    .unwrap();
+}
+
+fn kind_from_str(kind: &str) -> RevisionKind {
+    match kind.to_lowercase().as_str() {
+        "repack" => RevisionKind::Repack,
+        "rerip" => RevisionKind::Rerip,
+        _ => RevisionKind::Proper,
+    }
+}
+
+pub fn parse(name: String) -> (Option<Revision>, String) {
+    //Keep the fix marker in place, it is parsed below
+    let real = RE_REAL.is_match(&name);
+    let stripped = RE_REAL.replace(&name, "$fix").to_string();
+
+    if let Some(captures) = RE_FIX.captures(&stripped) {
+        let number = captures
+            .name("number")
+            .map_or(1, |m| m.as_str().parse::<i32>().unwrap_or(1));
+        let revision = Revision {
+            version: 1 + number + if real { 1 } else { 0 },
+            kind: kind_from_str(&captures["kind"]),
+            real,
+        };
+        let (_, stripped) = utils::find_and_strip_token(&stripped, vec![RE_FIX.clone()]);
+
+        return (Some(revision), stripped);
+    }
+
+    if let Some(captures) = RE_VERSION.captures(&stripped) {
+        let version = captures["version"].parse::<i32>().unwrap_or(1);
+        if version > 1 {
+            let revision = Revision {
+                version,
+                kind: RevisionKind::Version,
+                real: false,
+            };
+            let stripped = RE_VERSION
+                .replace(&stripped, "${episode}${after}")
+                .to_string();
+
+            return (Some(revision), stripped);
+        }
+    }
+
+    (None, stripped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parse_revision() {
+        let mut test_grid: HashMap<&str, (i32, RevisionKind, bool)> = HashMap::new();
+        test_grid.insert(
+            "The Simpsons S26E05 HDTV x264 PROPER-LOL [eztv]",
+            (2, RevisionKind::Proper, false),
+        );
+        test_grid.insert(
+            "Two and a Half Men S12E01 HDTV x264 REPACK-LOL [eztv]",
+            (2, RevisionKind::Repack, false),
+        );
+        test_grid.insert(
+            "Show.S01E01.REPACK2.720p.HDTV",
+            (3, RevisionKind::Repack, false),
+        );
+        test_grid.insert(
+            "Show.S01E01.REAL.PROPER.720p.HDTV",
+            (3, RevisionKind::Proper, true),
+        );
+        test_grid.insert(
+            "Movie.2019.RERIP.1080p.BluRay",
+            (2, RevisionKind::Rerip, false),
+        );
+        test_grid.insert(
+            "Naruto Shippuuden - 345v2",
+            (2, RevisionKind::Version, false),
+        );
+        test_grid.insert(
+            "[Group] Title - 05v3 [1080p].mkv",
+            (3, RevisionKind::Version, false),
+        );
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let revision = parse(key.to_string()).0.unwrap();
+
+            assert_eq!(
+                revision,
+                Revision {
+                    version: val.0,
+                    kind: val.1,
+                    real: val.2
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_no_revision() {
+        let testlist = [
+            "Real.Steel.2011.1080p.BluRay",
+            "A.Proper.Job.2019.720p",
+            "[Group] Title - 05v1 [1080p].mkv",
+            "Show.S01E01.720p.HDTV.x264-GRP",
+        ];
+
+        for s in testlist.iter() {
+            println!("Test item: {}", s);
+            assert_eq!(parse(s.to_string()).0, None);
+        }
+    }
+
+    #[test]
+    fn test_strip_revision() {
+        let mut test_grid: HashMap<&str, &str> = HashMap::new();
+        test_grid.insert(
+            "Show S01E05 HDTV x264 PROPER-LOL",
+            "Show S01E05 HDTV x264-LOL",
+        );
+        test_grid.insert("Show.S01E01.REAL.REPACK.720p", "Show.S01E01.720p");
+        test_grid.insert("Naruto Shippuuden - 345v2", "Naruto Shippuuden - 345");
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            assert_eq!(val, parse(key.to_string()).1);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+extern crate serde;
+
+use super::title;
+use super::utils;
+
+use regex::Regex;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Stereo3D {
+    SideBySide,
+    HalfSideBySide,
+    FullSideBySide,
+    OverUnder,
+    HalfOverUnder,
+    FullOverUnder,
+    MVC,
+    Unspecified,
+}
+
+impl serde::Serialize for Stereo3D {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match *self {
+            Stereo3D::SideBySide => serializer.serialize_unit_variant("Stereo3D", 0, "sbs"),
+            Stereo3D::HalfSideBySide => {
+                serializer.serialize_unit_variant("Stereo3D", 0, "half_sbs")
+            }
+            Stereo3D::FullSideBySide => {
+                serializer.serialize_unit_variant("Stereo3D", 0, "full_sbs")
+            }
+            Stereo3D::OverUnder => serializer.serialize_unit_variant("Stereo3D", 0, "ou"),
+            Stereo3D::HalfOverUnder => serializer.serialize_unit_variant("Stereo3D", 0, "half_ou"),
+            Stereo3D::FullOverUnder => serializer.serialize_unit_variant("Stereo3D", 0, "full_ou"),
+            Stereo3D::MVC => serializer.serialize_unit_variant("Stereo3D", 0, "mvc"),
+            Stereo3D::Unspecified => serializer.serialize_unit_variant("Stereo3D", 0, "3d"),
+        }
+    }
+}
+
+lazy_static! {
+    static ref LAYOUT_TABLE: Vec<(Regex, Stereo3D)> = vec![
+        (
+            utils::token_regex(r"(?i:(?:3D[\s._-]?)?(?:H|Half)[\s._-]?SBS)"),
+            Stereo3D::HalfSideBySide,
+        ),
+        (
+            utils::token_regex(r"(?i:(?:3D[\s._-]?)?(?:F|Full)[\s._-]?SBS)"),
+            Stereo3D::FullSideBySide,
+        ),
+        (utils::token_regex(r"(?i:(?:3D[\s._-]?)?SBS)"), Stereo3D::SideBySide),
+        (
+            utils::token_regex(r"(?i:(?:3D[\s._-]?)?(?:H|Half)[\s._-]?(?:OU|TAB))"),
+            Stereo3D::HalfOverUnder,
+        ),
+        (
+            utils::token_regex(r"(?i:(?:3D[\s._-]?)?(?:F|Full)[\s._-]?(?:OU|TAB))"),
+            Stereo3D::FullOverUnder,
+        ),
+        //Case sensitive: "ou" is a common french word
+        (utils::token_regex(r"(?:3D[\s._-]?)?(?:OU|TAB)"), Stereo3D::OverUnder),
+        (utils::token_regex(r"(?:3D[\s._-]?)?MVC"), Stereo3D::MVC),
+    ];
+    static ref RE_3D: Regex = utils::token_regex(r"3D");
+}
+
+//Find the stereoscopic 3D layout: "HSBS", "H-SBS", "Half-OU", "FSBS", "MVC" or a plain "3D" tag.
+//A plain "3D" before the title boundary is part of the title: "Step.Up.3D.2010"
+pub fn parse(name: String) -> (Option<Stereo3D>, String) {
+    for (reg, layout) in LAYOUT_TABLE.iter() {
+        if reg.is_match(&name) {
+            let (_, stripped) = utils::find_and_strip_token(&name, vec![reg.clone()]);
+            let (_, stripped) = strip_3d(&stripped);
+            return (Some(*layout), stripped);
+        }
+    }
+
+    match strip_3d(&name) {
+        (true, stripped) => (Some(Stereo3D::Unspecified), stripped),
+        (false, stripped) => (None, stripped),
+    }
+}
+
+//Strip a "3D" tag found after the title boundary
+fn strip_3d(name: &str) -> (bool, String) {
+    let boundary = match title::title_boundary(name) {
+        Some(boundary) => boundary,
+        None => return (false, name.to_string()),
+    };
+
+    match RE_3D.find_iter(name).find(|m| m.start() >= boundary) {
+        Some(m) => {
+            let stripped = format!(
+                "{}{}",
+                &name[..m.start()],
+                RE_3D.replace(&name[m.start()..], "${after}")
+            );
+            (true, stripped)
+        }
+        None => (false, name.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parse_stereo_3d() {
+        let mut test_grid: HashMap<&str, Option<Stereo3D>> = HashMap::new();
+        test_grid.insert(
+            "Avatar.2009.3D.HSBS.1080p.BluRay.x264",
+            Some(Stereo3D::HalfSideBySide),
+        );
+        test_grid.insert(
+            "Avatar.2009.1080p.3D.H-SBS.BluRay.x264",
+            Some(Stereo3D::HalfSideBySide),
+        );
+        test_grid.insert(
+            "Gravity.2013.3D.Half-OU.1080p.BluRay",
+            Some(Stereo3D::HalfOverUnder),
+        );
+        test_grid.insert(
+            "Gravity.2013.3D.FSBS.1080p.BluRay",
+            Some(Stereo3D::FullSideBySide),
+        );
+        test_grid.insert("Gravity.2013.3D.SBS.1080p", Some(Stereo3D::SideBySide));
+        test_grid.insert("Gravity.2013.3D.TAB.1080p", Some(Stereo3D::OverUnder));
+        test_grid.insert("Gravity.2013.1080p.BluRay.3D.MVC", Some(Stereo3D::MVC));
+        test_grid.insert("Gravity.2013.3D.1080p.BluRay", Some(Stereo3D::Unspecified));
+        test_grid.insert("Step.Up.3D.2010.1080p.BluRay", None);
+        test_grid.insert("Step Up 3D (2010) 1080p HSBS", Some(Stereo3D::HalfSideBySide));
+        test_grid.insert("Gravity.2013.1080p.BluRay", None);
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            assert_eq!(val, parse(key.to_string()).0);
+        }
+    }
+
+    #[test]
+    fn test_strip_stereo_3d() {
+        let mut test_grid: HashMap<&str, &str> = HashMap::new();
+        test_grid.insert("Avatar.2009.3D.HSBS.1080p", "Avatar.2009.1080p");
+        test_grid.insert("Gravity.2013.3D.1080p", "Gravity.2013.1080p");
+        test_grid.insert("Step.Up.3D.2010.1080p", "Step.Up.3D.2010.1080p");
+        test_grid.insert("Step Up 3D (2010) 1080p HSBS", "Step Up 3D (2010) 1080p");
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            assert_eq!(val, parse(key.to_string()).1);
+        }
+    }
+}
@@ -0,0 +1,173 @@
+extern crate serde;
+
+use regex::Regex;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum StreamingService {
+    Amazon,
+    AppleTVPlus,
+    BBCiPlayer,
+    Crunchyroll,
+    DisneyPlus,
+    HBO,
+    HBOMax,
+    Hulu,
+    Netflix,
+    ParamountPlus,
+    Peacock,
+    Stan,
+}
+
+impl serde::Serialize for StreamingService {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match *self {
+            StreamingService::Amazon => {
+                serializer.serialize_unit_variant("StreamingService", 0, "amazon")
+            }
+            StreamingService::AppleTVPlus => {
+                serializer.serialize_unit_variant("StreamingService", 0, "apple_tv_plus")
+            }
+            StreamingService::BBCiPlayer => {
+                serializer.serialize_unit_variant("StreamingService", 0, "bbc_iplayer")
+            }
+            StreamingService::Crunchyroll => {
+                serializer.serialize_unit_variant("StreamingService", 0, "crunchyroll")
+            }
+            StreamingService::DisneyPlus => {
+                serializer.serialize_unit_variant("StreamingService", 0, "disney_plus")
+            }
+            StreamingService::HBO => {
+                serializer.serialize_unit_variant("StreamingService", 0, "hbo")
+            }
+            StreamingService::HBOMax => {
+                serializer.serialize_unit_variant("StreamingService", 0, "hbo_max")
+            }
+            StreamingService::Hulu => {
+                serializer.serialize_unit_variant("StreamingService", 0, "hulu")
+            }
+            StreamingService::Netflix => {
+                serializer.serialize_unit_variant("StreamingService", 0, "netflix")
+            }
+            StreamingService::ParamountPlus => {
+                serializer.serialize_unit_variant("StreamingService", 0, "paramount_plus")
+            }
+            StreamingService::Peacock => {
+                serializer.serialize_unit_variant("StreamingService", 0, "peacock")
+            }
+            StreamingService::Stan => {
+                serializer.serialize_unit_variant("StreamingService", 0, "stan")
+            }
+        }
+    }
+}
+
+//Scene aliases for each service. Matching is case sensitive: "iP" and "NF" are too short to be
+//matched loosely
+const ALIASES: &[(&str, StreamingService)] = &[
+    ("AMZN", StreamingService::Amazon),
+    ("ATVP", StreamingService::AppleTVPlus),
+    ("iP", StreamingService::BBCiPlayer),
+    ("CR", StreamingService::Crunchyroll),
+    ("DSNP", StreamingService::DisneyPlus),
+    ("DSNY", StreamingService::DisneyPlus),
+    ("HBO", StreamingService::HBO),
+    ("HMAX", StreamingService::HBOMax),
+    ("HULU", StreamingService::Hulu),
+    ("NF", StreamingService::Netflix),
+    ("PMTP", StreamingService::ParamountPlus),
+    ("PCOK", StreamingService::Peacock),
+    ("STAN", StreamingService::Stan),
+];
+
+pub fn from_alias(alias: &str) -> Option<StreamingService> {
+    ALIASES
+        .iter()
+        .find(|(a, _)| *a == alias)
+        .map(|(_, service)| *service)
+}
+
+//Find a service alias right before or right after the WEB source marker found at start..end in name:
+//"AMZN.WEB-DL", "WEBRip.NF". Returns the service and name without the alias
+pub fn parse(name: &str, start: usize, end: usize) -> (Option<StreamingService>, String) {
+    lazy_static! {
+        static ref RE_ALIAS_BEFORE: Regex =
+            Regex::new(r"(?:^|[\s._\-\[\](){}])(?P<alias>[A-Za-z]+)[\s._-]$").unwrap();
+        static ref RE_ALIAS_AFTER: Regex =
+            Regex::new(r"^[\s._-](?P<alias>[A-Za-z]+)(?:[\s._\-\[\](){}]|$)").unwrap();
+    }
+
+    if let Some(captures) = RE_ALIAS_BEFORE.captures(&name[..start]) {
+        let alias = captures.name("alias").unwrap();
+        if let Some(service) = from_alias(alias.as_str()) {
+            let stripped = format!("{}{}", &name[..alias.start()], &name[start..]);
+            return (Some(service), stripped);
+        }
+    }
+
+    if let Some(captures) = RE_ALIAS_AFTER.captures(&name[end..]) {
+        let alias = captures.name("alias").unwrap();
+        if let Some(service) = from_alias(alias.as_str()) {
+            let stripped = format!("{}{}", &name[..end], &name[end + alias.end()..]);
+            return (Some(service), stripped);
+        }
+    }
+
+    (None, name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parse_streaming_service() {
+        let mut test_grid: HashMap<&str, Option<StreamingService>> = HashMap::new();
+        test_grid.insert(
+            "Show.S01E01.1080p.AMZN.WEB-DL",
+            Some(StreamingService::Amazon),
+        );
+        test_grid.insert("Show.S01E01.NF.WEBRip", Some(StreamingService::Netflix));
+        test_grid.insert(
+            "Show.S01E01.DSNP.WEB-DL",
+            Some(StreamingService::DisneyPlus),
+        );
+        test_grid.insert(
+            "Show.S01E01.ATVP.WEB-DL",
+            Some(StreamingService::AppleTVPlus),
+        );
+        test_grid.insert("Show.S01E01.HMAX.WEB-DL", Some(StreamingService::HBOMax));
+        test_grid.insert("Show S01E01 HULU WEBRip", Some(StreamingService::Hulu));
+        test_grid.insert("Show.S01E01.iP.WEB-DL", Some(StreamingService::BBCiPlayer));
+        test_grid.insert("Show.S01E01.WEB-DL.AMZN", Some(StreamingService::Amazon));
+        test_grid.insert("Show.S01E01.1080p.WEB-DL", None);
+        test_grid.insert("Show.S01E01.Nf.WEB-DL", None);
+
+        let re_web = Regex::new(r"WEB-?(?:DL|Rip)").unwrap();
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let web = re_web.find(key).unwrap();
+            let service = parse(key, web.start(), web.end()).0;
+
+            assert_eq!(val, service);
+        }
+    }
+
+    #[test]
+    fn test_strip_streaming_service() {
+        let mut test_grid: HashMap<&str, &str> = HashMap::new();
+        test_grid.insert("Show.S01E01.AMZN.WEB-DL.x264", "Show.S01E01.WEB-DL.x264");
+        test_grid.insert("Show.S01E01.WEB-DL.NF.x264", "Show.S01E01.WEB-DL.x264");
+
+        let re_web = Regex::new(r"WEB-?(?:DL|Rip)").unwrap();
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let web = re_web.find(key).unwrap();
+            assert_eq!(val, parse(key, web.start(), web.end()).1);
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use super::language;
+use super::language::Language;
+use super::utils;
+
+use regex::Regex;
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct Subtitles {
+    pub languages: Vec<Language>,
+    pub multi: bool,
+    pub hardcoded: bool,
+    pub soft: bool,
+}
+
+const LANGUAGE_TAGS: &str = "ar|ara|arabic|zh|chi|chs|cht|chinese|cz|cze|czech|da|dk|dan|danish|nl|dut|dutch|en|eng|english|fi|fin|finnish|fr|fre|fra|french|de|ger|deu|german|gr|gre|greek|he|heb|hebrew|hi|hin|hindi|hu|hun|hungarian|it|ita|italian|jp|jap|jpn|japanese|ko|kor|korean|no|nor|norwegian|pl|pol|polish|pt|por|portuguese|ru|rus|russian|es|esp|spa|spanish|sv|swe|swedish|tr|tur|turkish";
+
+lazy_static! {
+    static ref RE_LANGUAGE_SUBS: Vec<Regex> = vec![
+        utils::token_regex(&format!(
+            r"(?i:(?P<language>{})[\s._-]?sub(?:s|titles?|bed)?)",
+            LANGUAGE_TAGS
+        )),
+        utils::token_regex(&format!(
+            r"(?i:sub(?:s|titles?)?[\s._-]?(?P<language>{}))",
+            LANGUAGE_TAGS
+        )),
+    ];
+    static ref RE_MULTI_SUBS: Vec<Regex> =
+        vec![utils::token_regex(r"(?i:multi[\s._-]?sub(?:s|titles?)?)")];
+    static ref RE_HARDCODED: Vec<Regex> = vec![utils::token_regex(
+        r"HC|(?i:hard[\s._-]?(?:sub(?:s|bed)?|coded))"
+    )];
+    static ref RE_SOFT: Vec<Regex> = vec![utils::token_regex(r"(?i:soft[\s._-]?sub(?:s|bed)?)")];
+    //Original version with subtitles. Left in place for the language detector
+    static ref RE_ORIGINAL_WITH_SUBS: Regex = utils::token_regex(r"VOST(?P<language>FR)");
+}
+
+//Find subtitle language markers and strip them from name
+fn parse_languages(name: String) -> (Vec<Language>, String) {
+    let mut languages: Vec<Language> = Vec::new();
+    let mut stripped = name;
+
+    for capture in RE_ORIGINAL_WITH_SUBS.captures_iter(&stripped) {
+        if let Some(language) = language::from_tag(&capture["language"]) {
+            languages.push(language);
+        }
+    }
+
+    for reg in RE_LANGUAGE_SUBS.iter() {
+        for capture in reg.captures_iter(&stripped) {
+            if let Some(language) = language::from_tag(&capture["language"]) {
+                if !languages.contains(&language) {
+                    languages.push(language);
+                }
+            }
+        }
+        stripped = reg.replace_all(&stripped, "${after}").to_string();
+    }
+
+    (languages, stripped)
+}
+
+pub fn parse(name: String) -> (Option<Subtitles>, String) {
+    let (languages, stripped) = parse_languages(name);
+    let (multi, stripped) = utils::find_and_strip_token(&stripped, RE_MULTI_SUBS.to_vec());
+    let (hardcoded, stripped) = utils::find_and_strip_token(&stripped, RE_HARDCODED.to_vec());
+    let (soft, stripped) = utils::find_and_strip_token(&stripped, RE_SOFT.to_vec());
+
+    if languages.is_empty() && !multi && !hardcoded && !soft {
+        return (None, stripped);
+    }
+
+    (
+        Some(Subtitles {
+            languages,
+            multi,
+            hardcoded,
+            soft,
+        }),
+        stripped,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parse_subtitles() {
+        let mut test_grid: HashMap<&str, Subtitles> = HashMap::new();
+        test_grid.insert(
+            "Show.S01E01.VOSTFR.720p.WEB",
+            Subtitles {
+                languages: vec![Language::French],
+                multi: false,
+                hardcoded: false,
+                soft: false,
+            },
+        );
+        test_grid.insert(
+            "Movie.2019.720p.BluRay.x264.NLSubs-GRP",
+            Subtitles {
+                languages: vec![Language::Dutch],
+                multi: false,
+                hardcoded: false,
+                soft: false,
+            },
+        );
+        test_grid.insert(
+            "Movie.2019.1080p.ENG.SUBS.BluRay",
+            Subtitles {
+                languages: vec![Language::English],
+                multi: false,
+                hardcoded: false,
+                soft: false,
+            },
+        );
+        test_grid.insert(
+            "Movie.2019.SUBFRENCH.720p",
+            Subtitles {
+                languages: vec![Language::French],
+                multi: false,
+                hardcoded: false,
+                soft: false,
+            },
+        );
+        test_grid.insert(
+            "Movie.2019.Multi-Subs.1080p",
+            Subtitles {
+                languages: vec![],
+                multi: true,
+                hardcoded: false,
+                soft: false,
+            },
+        );
+        test_grid.insert(
+            "Movie.2019.HARDSUB.720p",
+            Subtitles {
+                languages: vec![],
+                multi: false,
+                hardcoded: true,
+                soft: false,
+            },
+        );
+        test_grid.insert(
+            "Lucy.2014.HC.HDRip.XViD-juggs[ETRG]",
+            Subtitles {
+                languages: vec![],
+                multi: false,
+                hardcoded: true,
+                soft: false,
+            },
+        );
+        test_grid.insert(
+            "[Group] Title - 01 [Softsubs][1080p]",
+            Subtitles {
+                languages: vec![],
+                multi: false,
+                hardcoded: false,
+                soft: true,
+            },
+        );
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let subtitles = parse(key.to_string()).0.unwrap();
+
+            assert_eq!(val, subtitles);
+        }
+    }
+
+    #[test]
+    fn test_parse_no_subtitles() {
+        let testlist = [
+            "[SubsPlease] One Piece - 1071 [1080p].mkv",
+            "Movie.Title.2014.1080p.BluRay",
+            "Hercules.2014.EXTENDED.HDRip.XViD-juggs[ETRG]",
+        ];
+
+        for s in testlist.iter() {
+            println!("Test item: {}", s);
+            assert_eq!(parse(s.to_string()).0, None);
+        }
+    }
+
+    #[test]
+    fn test_strip_subtitles() {
+        let mut test_grid: HashMap<&str, &str> = HashMap::new();
+        test_grid.insert("Movie.2019.720p.x264.NLSubs-GRP", "Movie.2019.720p.x264-GRP");
+        test_grid.insert("Show.S01E01.VOSTFR.720p", "Show.S01E01.VOSTFR.720p");
+        test_grid.insert("Movie.2019.HC.HDRip", "Movie.2019.HDRip");
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            assert_eq!(val, parse(key.to_string()).1);
+        }
+    }
+}
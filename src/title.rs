@@ -2,17 +2,54 @@ use regex::Regex;
 use std::ffi::OsStr;
 use std::path::Path;
 
+use super::air_date;
+use super::country;
+use super::country::Country;
 use super::episode;
+use super::language;
+use super::part;
+use super::streaming_service;
 use super::year;
 use super::MediaType;
 
-fn parse_title_from_filename(name: &str) -> String {
-    lazy_static! {
-        static ref RE_SQUARE_BLOCKS: Regex = Regex::new(r"(?i)\[.*\]").unwrap();
-        static ref RE_PARENTHESIS: Regex = Regex::new(r"(?i)\(.*\)").unwrap();
-        static ref RE_DELIMITERS: Regex = Regex::new(r"(?i)[_\.\(\)\[\]]").unwrap();
+//Find the episode marker in name: season/episode number, special episode marker, anime absolute episode number or daily show
+//air date. Returns the start and end offsets of the first one
+fn episode_marker(name: &str) -> Option<(usize, usize)> {
+    let mut positions: Vec<(usize, usize)> = Vec::new();
+
+    for reg in [
+        &*episode::RE_SEASON_AND_EPISODE,
+        &*episode::RE_SEASON_AND_EPISODE_SEPARATED,
+        &*episode::RE_SEASON_MARKER,
+        &*episode::RE_EPISODE,
+    ]
+    .iter()
+    {
+        if let Some(m) = reg.find(name) {
+            positions.push((m.start(), m.end()));
+        }
     }
+    if episode::parse_absolute(name) != 0 {
+        if let Some(m) = episode::RE_ABSOLUTE_EPISODE.find(name) {
+            positions.push((m.start(), m.end()));
+        }
+    }
+    if let Some(position) = air_date::position(name) {
+        positions.push(position);
+    }
+    if let Some(position) = episode::special_position(name) {
+        positions.push(position);
+    }
+
+    //Markers starting at the same offset overlap ("S01" and "S01E05"): keep the longest one
+    positions
+        .into_iter()
+        .filter(|(start, _)| *start > 0)
+        .min_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)))
+}
 
+//Offset where the title ends in name: the year, episode marker or file part, whichever comes first
+pub fn title_boundary(name: &str) -> Option<usize> {
     let mut offsets: Vec<usize> = Vec::new();
 
     //Find year string in name. Everything after it is usually not part of the title
@@ -21,42 +58,40 @@ fn parse_title_from_filename(name: &str) -> String {
         name.rfind(&year_str).unwrap_or(0)
     } else {
         0
-    } as usize;
+    };
 
     offsets.push(year_offset);
 
-    //Find season/episode number in name. Everything after it is usually not part of the title
-    offsets.push(
-        episode::RE_SEASON_AND_EPISODE
-            .captures(name)
-            .map_or(0, |m| m.get(0).map_or(0, |c| c.start())),
-    );
-    offsets.push(
-        episode::RE_SEASON_AND_EPISODE_SEPARATED
-            .captures(name)
-            .map_or(0, |m| m.get(0).map_or(0, |c| c.start())),
-    );
-    offsets.push(
-        episode::RE_SEASON
-            .captures(name)
-            .map_or(0, |m| m.get(0).map_or(0, |c| c.start())),
-    );
-    offsets.push(
-        episode::RE_EPISODE
-            .captures(name)
-            .map_or(0, |m| m.get(0).map_or(0, |c| c.start())),
-    );
-
-    let min_offset: usize = offsets.into_iter().filter(|x| *x > 0).min().unwrap_or(0);
+    //Find season/episode number or air date in name. Everything after it is usually not part of the title
+    offsets.push(episode_marker(name).map_or(0, |(start, _)| start));
+    offsets.push(episode::RE_COMPLETE_SERIES.find(name).map_or(0, |m| m.start()));
+    //Same for file part and disc numbers of multi-file movies
+    offsets.push(part::position(name).unwrap_or(0));
+
+    offsets.into_iter().filter(|x| *x > 0).min()
+}
 
+//Part of the name preceding the title boundary, where the title usually is
+fn title_portion(name: &str) -> &str {
     let mut work_str = name;
 
-    if min_offset != 0 {
-        work_str = &work_str[..min_offset];
+    if let Some(offset) = title_boundary(name) {
+        work_str = &work_str[..offset];
     }
     let file_path: Vec<&OsStr> = Path::new(work_str).iter().collect();
-    let filename_from_path = file_path[file_path.len() - 1].to_str().unwrap();
-    work_str = filename_from_path;
+
+    file_path[file_path.len() - 1].to_str().unwrap()
+}
+
+fn parse_title_from_filename(name: &str) -> String {
+    lazy_static! {
+        static ref RE_SQUARE_BLOCKS: Regex = Regex::new(r"(?i)\[.*\]").unwrap();
+        static ref RE_PARENTHESIS: Regex = Regex::new(r"(?i)\(.*\)").unwrap();
+        static ref RE_DELIMITERS: Regex = Regex::new(r"(?i)[_\.\(\)\[\]]").unwrap();
+    }
+
+    //Remove country marker ("The.Office.US", "Shameless (UK)"), parsed separately
+    let (_, work_str) = country::parse(title_portion(name));
 
     //Remove square brackets blocks
     let strip_blocks = RE_SQUARE_BLOCKS.replace_all(&work_str, "").to_string();
@@ -69,16 +104,44 @@ fn parse_title_from_filename(name: &str) -> String {
         .replace_all(&strip_parenthesis, " ")
         .to_string();
 
-    strip_delimiters.trim().to_string()
+    //Dash separating the title from a marker that is not a number: "Title - NCOP1"
+    strip_delimiters
+        .trim_matches(|c: char| c == '-' || c.is_whitespace())
+        .to_string()
 }
 
-pub fn parse(name: &str, media_type: Option<MediaType>) -> String {
+//Split a title on AKA markers and pipe separators: "Amelie AKA Le Fabuleux Destin d Amelie Poulain".
+//The first part is the primary title, the others are alternative titles
+fn split_alternative_titles(title: String) -> (String, Vec<String>) {
+    lazy_static! {
+        static ref RE_ALTERNATIVE_SEPARATOR: Regex =
+            Regex::new(r"(?i)\s+(?:aka|a\s?k\s?a)\s+|\s*\|\s*").unwrap();
+    }
+
+    let mut titles = RE_ALTERNATIVE_SEPARATOR
+        .split(&title)
+        .map(|part| part.trim().to_string())
+        .filter(|part| !part.is_empty());
+
+    let primary = titles.next().unwrap_or_default();
+
+    (primary, titles.collect())
+}
+
+pub fn parse(name: &str, media_type: Option<MediaType>) -> (String, Vec<String>) {
+    split_alternative_titles(parse_title(name, media_type))
+}
+
+fn parse_title(name: &str, media_type: Option<MediaType>) -> String {
     lazy_static! {
         static ref RE_CAPS: Regex = Regex::new(r"[A-Z]").unwrap();
     }
 
     let file_path: Vec<&OsStr> = Path::new(name).iter().collect();
-    let filename_from_path = file_path[file_path.len() - 1].to_str().unwrap();
+    let filename_from_path = match file_path.last() {
+        Some(part) => part.to_str().unwrap(),
+        None => return String::new(),
+    };
 
     let filepath_shift :usize = match media_type {
         Some(MediaType::Movie) => 2,
@@ -89,12 +152,12 @@ pub fn parse(name: &str, media_type: Option<MediaType>) -> String {
         .clone()
         .into_iter()
         .map(|part| {
-            parse_title_from_filename(&(part.to_str().unwrap()))
+            parse_title_from_filename(part.to_str().unwrap())
         })
         .rev()
         .collect();
 
-    let contains_caps :Vec<String> = str_title.into_iter().filter(|x| RE_CAPS.is_match(&x)).collect();
+    let contains_caps :Vec<String> = str_title.into_iter().filter(|x| RE_CAPS.is_match(x)).collect();
 
     //Heuristic: filepath parts that contains caps may contain media title. This is useful when parsing full filepaths. For example: "/var/lib/flemzerd/library/shows/rick_and_morty/season_3/s03e10/Rick and Morty S03E10 720p HDTV x264-BATV/Rick.and.Morty.S03E10.720p.HDTV.x264-BATV[eztv].mkv"
     if !contains_caps.is_empty() && file_path.len() > 1 {
@@ -104,14 +167,126 @@ pub fn parse(name: &str, media_type: Option<MediaType>) -> String {
     if file_path.len() >= filepath_shift {
         let title_part_from_filepath = file_path[file_path.len() - filepath_shift].to_str().unwrap();
 
-        return parse_title_from_filename(&title_part_from_filepath);
+        return parse_title_from_filename(title_part_from_filepath);
     }
 
     parse_title_from_filename(filename_from_path)
 }
 
+//Find the country qualifier of the title: "The.Office.US.S01E01" gives the United States.
+//Path parts are searched from the file name up, like the title itself
+pub fn parse_country(name: &str) -> Option<Country> {
+    Path::new(name)
+        .iter()
+        .rev()
+        .filter_map(|part| country::parse(title_portion(part.to_str().unwrap())).0)
+        .next()
+}
+
+fn is_technical_token(token: &str) -> bool {
+    lazy_static! {
+        static ref RE_TECHNICAL: Regex = Regex::new(
+            r"(?i)^(?:\d{3,4}[pi]|\d{1,2}-?bits?|(?:hd|pd|sd)tv|web(?:-?(?:dl|rip|cap))?|blu-?ray|b[dr]rip|dvd(?:rip|r|scr)?|hdrip|remux|uhd|[xh]26[2345]|hevc|av1|xvid|divx|avc|aac|e?ac3|ddp?\d?|dts(?:-?hd)?|truehd|atmos|flac|opus|mkv|mp4|avi|m4v|hdr(?:10)?|hfr|\d{2,3}fps|(?:19|20)\d{2})(?:[-\[(+]|$)"
+        ).unwrap();
+        static ref RE_SCENE_TAG: Regex = Regex::new(
+            r"^(?:PROPER|REPACK|RERIP|REAL|iNTERNAL|INTERNAL|LIMITED|COMPLETE|MULTi|MULTI|TRUEFRENCH|VOSTFR|SUBBED|DUBBED)(?:[-\[(]|$)"
+        ).unwrap();
+        static ref RE_UPPERCASE: Regex = Regex::new(r"^[A-Z]{3,}$").unwrap();
+    }
+
+    token.starts_with('[')
+        || token.starts_with('(')
+        || RE_TECHNICAL.is_match(token)
+        || RE_SCENE_TAG.is_match(token)
+        || streaming_service::from_alias(token).is_some()
+        || (RE_UPPERCASE.is_match(token) && language::from_tag(token).is_some())
+}
+
+//End offset of the marker of a single episode: "S01E05", "1x05", an air date or an anime absolute
+//episode number. Season packs and movies have none
+fn single_episode_marker(name: &str) -> Option<usize> {
+    lazy_static! {
+        static ref RE_SINGLE_EPISODE: Regex = Regex::new(
+            r"(?i)(?:^|[\s._\-\[(])(?P<marker>s\d{1,3}[\s._-]?ep?\d{1,3}|\d{1,2}x\d{1,3})(?:\D|$)"
+        )
+        .unwrap();
+    }
+
+    let mut positions: Vec<(usize, usize)> = Vec::new();
+
+    if let Some(m) = RE_SINGLE_EPISODE
+        .captures(name)
+        .and_then(|captures| captures.name("marker"))
+    {
+        positions.push((m.start(), m.end()));
+    }
+    if let Some(position) = air_date::position(name) {
+        positions.push(position);
+    }
+    if episode::parse_absolute(name) != 0 {
+        if let Some(m) = episode::RE_ABSOLUTE_EPISODE.find(name) {
+            positions.push((m.start(), m.end()));
+        }
+    }
+
+    positions
+        .into_iter()
+        .filter(|(start, _)| *start > 0)
+        .min()
+        .map(|(_, end)| end)
+}
+
+//Part of the name following the episode marker and its additional episodes: "S01E01-03", "1x01-1x02"
+fn after_episode_marker(name: &str) -> Option<&str> {
+    let file_path: Vec<&OsStr> = Path::new(name).iter().collect();
+    let filename = file_path.last()?.to_str().unwrap();
+
+    let marker_end = single_episode_marker(filename)?;
+    let marker_end = match episode::multi_episode_end(filename) {
+        Some(end) if end > marker_end => end,
+        _ => marker_end,
+    };
+
+    Some(&filename[marker_end..])
+}
+
+//Find the episode title: the text between the episode marker and the first technical token.
+//"Gotham.S01E05.Viper.WEB-DL.x264.AAC" gives "Viper", "Show - S01E02 - The Title.mkv" gives "The Title".
+//The title ends where `technical`, the name with its technical tokens stripped by the detectors,
+//stops matching the name: "Show.S01E01.Title.H.264-GRP" gives "Title"
+pub fn parse_episode_title(name: &str, technical: &str) -> String {
+    lazy_static! {
+        static ref RE_TOKEN_DELIMITERS: Regex = Regex::new(r"[\s._]+").unwrap();
+    }
+
+    let mut rest = match after_episode_marker(name) {
+        Some(rest) => rest,
+        None => return String::new(),
+    };
+    if let Some(technical_rest) = after_episode_marker(technical) {
+        let common_end = rest
+            .char_indices()
+            .zip(technical_rest.chars())
+            .find(|((_, c), technical_c)| c != technical_c)
+            .map_or(rest.len().min(technical_rest.len()), |((i, _), _)| i);
+        rest = &rest[..common_end];
+    }
+
+    let tokens: Vec<&str> = RE_TOKEN_DELIMITERS
+        .split(rest)
+        .filter(|token| !token.is_empty())
+        .take_while(|token| !is_technical_token(token))
+        .collect();
+
+    tokens
+        .join(" ")
+        .trim_matches(|c: char| c == '-' || c.is_whitespace())
+        .to_string()
+}
+
 #[cfg(test)]
 mod tests {
+    use super::Country;
     use std::collections::HashMap;
 
     #[test]
@@ -121,10 +296,95 @@ mod tests {
         test_grid.insert("Show.title.S01E01.HDTV-Blablabla", "Show title");
         test_grid.insert("Show.title.2018.S01E01.HDTV-Blablabla", "Show title");
         test_grid.insert("Movie.title.2018.HDTV-Blablabla", "Movie title");
+        test_grid.insert("The.Office.US.S01E01.720p.HDTV", "The Office");
+        test_grid.insert("Shameless (UK) S01E01 720p HDTV", "Shameless");
+        test_grid.insert("[Group] Title - NCOP1 [1080p].mkv", "Title");
+        test_grid.insert("Doctor.Who.2005.Christmas.Special.720p.HDTV", "Doctor Who");
+        test_grid.insert("House of Cards 2013 S01E01 720p", "House of Cards");
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let title = super::parse(key, None).0;
+
+            assert_eq!(val, title);
+        }
+    }
+
+    #[test]
+    fn test_parse_alternative_titles() {
+        let mut test_grid: HashMap<&str, (&str, Vec<&str>)> = HashMap::new();
+
+        test_grid.insert(
+            "Amelie.AKA.Le.Fabuleux.Destin.d.Amelie.Poulain.2001.1080p",
+            ("Amelie", vec!["Le Fabuleux Destin d Amelie Poulain"]),
+        );
+        test_grid.insert(
+            "Amelie.a.k.a.Le.Fabuleux.Destin.d.Amelie.Poulain.2001.1080p",
+            ("Amelie", vec!["Le Fabuleux Destin d Amelie Poulain"]),
+        );
+        test_grid.insert(
+            "Ghost in the Shell | Koukaku Kidoutai (1995) 1080p",
+            ("Ghost in the Shell", vec!["Koukaku Kidoutai"]),
+        );
+        test_grid.insert("Movie.title.2018.HDTV-Blablabla", ("Movie title", vec![]));
+        test_grid.insert("Akame.ga.Kill.S01E01.720p", ("Akame ga Kill", vec![]));
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let (title, alternative_titles) = super::parse(key, None);
+
+            assert_eq!(val.0, title);
+            assert_eq!(val.1, alternative_titles);
+        }
+    }
+
+    #[test]
+    fn test_parse_country() {
+        let mut test_grid: HashMap<&str, Option<Country>> = HashMap::new();
+
+        test_grid.insert("The.Office.US.S01E01.720p.HDTV", Some(Country::UnitedStates));
+        test_grid.insert("Shameless (UK) S01E01 720p HDTV", Some(Country::UnitedKingdom));
+        test_grid.insert(
+            "/tv/Shameless (US)/Season 1/Shameless.S01E01.720p.mkv",
+            Some(Country::UnitedStates),
+        );
+        test_grid.insert("House.of.Cards.2013.S01E01.720p.WEB-DL", None);
+        test_grid.insert("Let.Us.Prey.2014.720p.BluRay", None);
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            assert_eq!(val, super::parse_country(key));
+        }
+    }
+
+    #[test]
+    fn test_parse_episode_title() {
+        let mut test_grid: HashMap<&str, &str> = HashMap::new();
+
+        test_grid.insert("Gotham.S01E05.Viper.WEB-DL.x264.AAC", "Viper");
+        test_grid.insert("Show - S01E02 - The Title.mkv", "The Title");
+        test_grid.insert("Show.S01E01E02.Pilot.Part.One.720p.HDTV", "Pilot Part One");
+        test_grid.insert("Show.2x05.Spider-Man.Returns.1080p.AMZN.WEB-DL", "Spider-Man Returns");
+        test_grid.insert("The.Daily.Show.2019.10.21.Guest.Name.720p.WEB", "Guest Name");
+        test_grid.insert("[Group] Title - 05 - The Episode [1080p].mkv", "The Episode");
+        test_grid.insert("The Simpsons S26E05 HDTV x264 PROPER-LOL [eztv]", "");
+        test_grid.insert("Show.S01E01.FRENCH.720p.HDTV", "");
+        test_grid.insert("Movie.title.2018.HDTV-Blablabla", "");
+        test_grid.insert("Show.S01E01.The.Race.1080p.50fps.WEB-DL", "The Race");
+        test_grid.insert("Show.S01E01.The.Race.50fps.WEB-DL", "The Race");
+        test_grid.insert("Show Season 1-3 1080p", "");
+        test_grid.insert("Show.S01-S05.BluRay", "");
+        test_grid.insert("Show Complete Series 720p", "");
+        test_grid.insert("Avengers 2012 1080p", "");
+        test_grid.insert("House of Cards 2013 S01E01 720p", "");
+        test_grid.insert("Se7en.1995.REMASTERED", "");
+        test_grid.insert("Avatar 2009 3D H-SBS 1080p", "");
+        test_grid.insert("Show.S01E01-03.1080p", "");
+        test_grid.insert("Show 1x01-1x02 HDTV", "");
 
         for (key, val) in test_grid {
             println!("Test item: {}", key);
-            let title = super::parse(key, None);
+            let title = super::parse_episode_title(key, key);
 
             assert_eq!(val, title);
         }
@@ -9,3 +9,25 @@ pub fn find_and_strip(name: &str, regex_table: Vec<Regex>) -> (bool, String) {
     }
     (false, name.to_string())
 }
+
+//Build a regex matching pattern as a whole token, delimited by separators or string boundaries.
+//Case sensitive on purpose: scene tags such as "FRENCH" are uppercase, title words are not
+pub fn token_regex(pattern: &str) -> Regex {
+    Regex::new(&format!(
+        r"(?:^|[\s._\-\[\](){{}}/])(?:{})(?P<after>[\s._\-\[\](){{}}/]|$)",
+        pattern
+    ))
+    .unwrap()
+}
+
+//Same as find_and_strip for regexes built with token_regex: the trailing separator is kept so
+//that surrounding tokens stay separated and "x264.PROPER-GROUP" keeps its release group dash
+pub fn find_and_strip_token(name: &str, regex_table: Vec<Regex>) -> (bool, String) {
+    for reg in regex_table {
+        if reg.is_match(name) {
+            let stripped = &reg.replace_all(name, "${after}");
+            return (true, stripped.to_string());
+        }
+    }
+    (false, name.to_string())
+}
@@ -5,6 +5,7 @@ use super::utils;
 use regex::Regex;
 
 #[derive(Debug, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum VideoCodec {
     DIVX,
     XVID,
@@ -12,6 +13,22 @@ pub enum VideoCodec {
     H263,
     H264,
     H265,
+    AV1,
+    VP8,
+    VP9,
+    VC1,
+    ProRes,
+    MPEG1,
+}
+
+#[derive(Debug, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum HdrFormat {
+    HDR,
+    HDR10,
+    HDR10Plus,
+    DolbyVision,
+    HLG,
 }
 
 impl serde::Serialize for VideoCodec {
@@ -26,73 +43,153 @@ impl serde::Serialize for VideoCodec {
             VideoCodec::H263 => serializer.serialize_unit_variant("VideoCodec", 0, "h263"),
             VideoCodec::H264 => serializer.serialize_unit_variant("VideoCodec", 0, "h264"),
             VideoCodec::H265 => serializer.serialize_unit_variant("VideoCodec", 0, "h265"),
+            VideoCodec::AV1 => serializer.serialize_unit_variant("VideoCodec", 0, "av1"),
+            VideoCodec::VP8 => serializer.serialize_unit_variant("VideoCodec", 0, "vp8"),
+            VideoCodec::VP9 => serializer.serialize_unit_variant("VideoCodec", 0, "vp9"),
+            VideoCodec::VC1 => serializer.serialize_unit_variant("VideoCodec", 0, "vc1"),
+            VideoCodec::ProRes => serializer.serialize_unit_variant("VideoCodec", 0, "prores"),
+            VideoCodec::MPEG1 => serializer.serialize_unit_variant("VideoCodec", 0, "mpeg1"),
         }
     }
 }
 
-pub fn parse(name: String) -> (Option<VideoCodec>, String) {
+impl serde::Serialize for HdrFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match *self {
+            HdrFormat::HDR => serializer.serialize_unit_variant("HdrFormat", 0, "hdr"),
+            HdrFormat::HDR10 => serializer.serialize_unit_variant("HdrFormat", 0, "hdr10"),
+            HdrFormat::HDR10Plus => serializer.serialize_unit_variant("HdrFormat", 0, "hdr10_plus"),
+            HdrFormat::DolbyVision => {
+                serializer.serialize_unit_variant("HdrFormat", 0, "dolby_vision")
+            }
+            HdrFormat::HLG => serializer.serialize_unit_variant("HdrFormat", 0, "hlg"),
+        }
+    }
+}
+
+//Returns the codec and whether the token named the encoder ("x264", "XviD") rather than the
+//format ("H.264", "AVC"). Encoder names are used for re-encodes, format names for untouched streams
+pub fn parse(name: String) -> (Option<VideoCodec>, bool, String) {
     lazy_static! {
+        static ref RE_H262_ENCODER: Vec<Regex> = vec![Regex::new(r"(?i)x[\s.]?262").unwrap(),];
         static ref RE_H262: Vec<Regex> = vec![
             Regex::new(r"(?i)MP[E]?G[-]?2").unwrap(),
-            Regex::new(r"(?i)x?262").unwrap(),
+            Regex::new(r"(?i)(?:H[\s.]?)?262").unwrap(),
         ];
         static ref RE_DIVX: Vec<Regex> = vec![Regex::new(r"(?i)DIV(\s)?X").unwrap(),];
         static ref RE_XVID: Vec<Regex> = vec![Regex::new(r"(?i)X(\s)?VID").unwrap(),];
-        static ref RE_H263: Vec<Regex> = vec![Regex::new(r"(?i)x?263").unwrap(),];
+        static ref RE_H263_ENCODER: Vec<Regex> = vec![Regex::new(r"(?i)x[\s.]?263").unwrap(),];
+        static ref RE_H263: Vec<Regex> = vec![Regex::new(r"(?i)(?:H[\s.]?)?263").unwrap(),];
+        static ref RE_H264_ENCODER: Vec<Regex> = vec![Regex::new(r"(?i)x[\s.]?264").unwrap(),];
         static ref RE_H264: Vec<Regex> = vec![
             Regex::new(r"(?i)MP[E]?G[-]?4").unwrap(),
-            Regex::new(r"(?i)x?264").unwrap(),
+            Regex::new(r"(?i)(?:H[\s.]?)?264").unwrap(),
             Regex::new(r"(?i)AVC(HD)?").unwrap(),
         ];
-        static ref RE_H265: Vec<Regex> = vec![Regex::new(r"(?i)x?265").unwrap(),];
+        static ref RE_H265_ENCODER: Vec<Regex> = vec![Regex::new(r"(?i)x[\s.]?265").unwrap(),];
+        static ref RE_H265: Vec<Regex> = vec![
+            Regex::new(r"(?i)(?:H[\s.]?)?265").unwrap(),
+            Regex::new(r"(?i)HEVC").unwrap(),
+        ];
+        static ref RE_AV1: Vec<Regex> = vec![Regex::new(r"(?i)AV1").unwrap(),];
+        static ref RE_VP8: Vec<Regex> = vec![Regex::new(r"(?i)VP8").unwrap(),];
+        static ref RE_VP9: Vec<Regex> = vec![Regex::new(r"(?i)VP9").unwrap(),];
+        static ref RE_VC1: Vec<Regex> = vec![Regex::new(r"(?i)VC[-\s]?1").unwrap(),];
+        static ref RE_PRORES: Vec<Regex> = vec![Regex::new(r"(?i)Pro[-\s]?Res").unwrap(),];
+        static ref RE_MPEG1: Vec<Regex> = vec![utils::token_regex(r"(?i:MP[E]?G[-]?1)"),];
     }
 
-    let mut matched_codec: Option<VideoCodec> = None;
-    let original_name = name.clone();
-
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_H265.to_vec());
-    if matched {
-        matched_codec = Some(VideoCodec::H265);
+    //MPEG-1 goes first: its "-1" would otherwise be left for the release group
+    let codecs: Vec<(&Vec<Regex>, VideoCodec, bool)> = vec![
+        (&RE_MPEG1, VideoCodec::MPEG1, false),
+        (&RE_H265_ENCODER, VideoCodec::H265, true),
+        (&RE_H265, VideoCodec::H265, false),
+        (&RE_H264_ENCODER, VideoCodec::H264, true),
+        (&RE_H264, VideoCodec::H264, false),
+        (&RE_H263_ENCODER, VideoCodec::H263, true),
+        (&RE_H263, VideoCodec::H263, false),
+        (&RE_DIVX, VideoCodec::DIVX, true),
+        (&RE_XVID, VideoCodec::XVID, true),
+        (&RE_H262_ENCODER, VideoCodec::H262, true),
+        (&RE_H262, VideoCodec::H262, false),
+        (&RE_AV1, VideoCodec::AV1, false),
+        (&RE_VP9, VideoCodec::VP9, false),
+        (&RE_VP8, VideoCodec::VP8, false),
+        (&RE_VC1, VideoCodec::VC1, false),
+        (&RE_PRORES, VideoCodec::ProRes, false),
+    ];
 
-        return (matched_codec, stripped_name);
+    for (regex_table, codec, encoder) in codecs {
+        let (matched, stripped_name) = utils::find_and_strip_token(&name, regex_table.to_vec());
+        if matched {
+            return (Some(codec), encoder, stripped_name);
+        }
     }
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_H264.to_vec());
-    if matched {
-        matched_codec = Some(VideoCodec::H264);
+    (None, false, name)
+}
 
-        return (matched_codec, stripped_name);
+//Find every HDR format in name: hybrid releases carry both Dolby Vision and HDR10 layers.
+//Formats are matched as whole tokens so that "DV" does not eat into audio tags like "DD5.1"
+pub fn parse_hdr(name: String) -> (Vec<HdrFormat>, String) {
+    lazy_static! {
+        static ref RE_HDR10_PLUS: Vec<Regex> =
+            vec![utils::token_regex(r"(?i:hdr10(?:\+|[\s._-]?plus))")];
+        static ref RE_HDR10: Vec<Regex> = vec![utils::token_regex(r"(?i:hdr10)")];
+        static ref RE_DOLBY_VISION: Vec<Regex> = vec![
+            utils::token_regex(r"DV|(?i:dovi)"),
+            utils::token_regex(r"(?i:dolby[\s._-]?vision)"),
+        ];
+        static ref RE_HLG: Vec<Regex> = vec![utils::token_regex(r"HLG")];
+        static ref RE_HDR: Vec<Regex> = vec![utils::token_regex(r"(?i:hdr)")];
     }
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_H263.to_vec());
-    if matched {
-        matched_codec = Some(VideoCodec::H263);
+    let formats: Vec<(&Vec<Regex>, HdrFormat)> = vec![
+        (&RE_DOLBY_VISION, HdrFormat::DolbyVision),
+        (&RE_HDR10_PLUS, HdrFormat::HDR10Plus),
+        (&RE_HDR10, HdrFormat::HDR10),
+        (&RE_HLG, HdrFormat::HLG),
+        (&RE_HDR, HdrFormat::HDR),
+    ];
 
-        return (matched_codec, stripped_name);
-    }
+    let mut matched_formats: Vec<HdrFormat> = Vec::new();
+    let mut stripped = name;
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_DIVX.to_vec());
-    if matched {
-        matched_codec = Some(VideoCodec::DIVX);
-
-        return (matched_codec, stripped_name);
+    for (regex_table, format) in formats {
+        let (matched, stripped_name) = utils::find_and_strip_token(&stripped, regex_table.to_vec());
+        if matched {
+            matched_formats.push(format);
+        }
+        stripped = stripped_name;
     }
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_XVID.to_vec());
-    if matched {
-        matched_codec = Some(VideoCodec::XVID);
+    (matched_formats, stripped)
+}
 
-        return (matched_codec, stripped_name);
+//Find the video bit depth: "10bit", "10-bit", "Hi10P", "12bit". Returns 0 if not specified
+pub fn parse_bit_depth(name: String) -> (i32, String) {
+    lazy_static! {
+        static ref RE_12BIT: Vec<Regex> = vec![utils::token_regex(r"(?i:12[\s._-]?bits?)")];
+        static ref RE_10BIT: Vec<Regex> = vec![
+            utils::token_regex(r"(?i:10[\s._-]?bits?)"),
+            utils::token_regex(r"(?i:hi10p?)"),
+        ];
+        static ref RE_8BIT: Vec<Regex> = vec![utils::token_regex(r"(?i:8[\s._-]?bits?)")];
     }
 
-    let (matched, stripped_name) = utils::find_and_strip(&name, RE_H262.to_vec());
-    if matched {
-        matched_codec = Some(VideoCodec::H262);
+    let depths: Vec<(&Vec<Regex>, i32)> = vec![(&RE_12BIT, 12), (&RE_10BIT, 10), (&RE_8BIT, 8)];
 
-        return (matched_codec, stripped_name);
+    for (regex_table, depth) in depths {
+        let (matched, stripped_name) = utils::find_and_strip_token(&name, regex_table.to_vec());
+        if matched {
+            return (depth, stripped_name);
+        }
     }
 
-    (matched_codec, original_name)
+    (0, name)
 }
 
 #[cfg(test)]
@@ -149,6 +246,25 @@ mod tests {
         test_grid.insert("x.265", VideoCodec::H265);
         test_grid.insert("-h 265", VideoCodec::H265);
         test_grid.insert("-x265", VideoCodec::H265);
+        test_grid.insert("HEVC", VideoCodec::H265);
+        test_grid.insert("HEVC-10bit", VideoCodec::H265);
+
+        test_grid.insert("AV1", VideoCodec::AV1);
+        test_grid.insert("[1080p AV1 OPUS]", VideoCodec::AV1);
+
+        test_grid.insert("VP8", VideoCodec::VP8);
+        test_grid.insert("VP9", VideoCodec::VP9);
+        test_grid.insert("vp9-opus", VideoCodec::VP9);
+
+        test_grid.insert("VC-1", VideoCodec::VC1);
+        test_grid.insert("VC1", VideoCodec::VC1);
+
+        test_grid.insert("ProRes", VideoCodec::ProRes);
+        test_grid.insert("PRORES.422", VideoCodec::ProRes);
+
+        test_grid.insert("MPEG-1", VideoCodec::MPEG1);
+        test_grid.insert("mpeg1.VCD", VideoCodec::MPEG1);
+        test_grid.insert("Movie.2019.ProRes.MPEG-1", VideoCodec::MPEG1);
 
         for (key, val) in test_grid {
             println!("Test item: {}", key);
@@ -157,4 +273,84 @@ mod tests {
             assert_eq!(val, video_codec);
         }
     }
+
+    #[test]
+    fn test_parse_encoder() {
+        let mut test_grid: HashMap<&str, bool> = HashMap::new();
+
+        test_grid.insert("x264", true);
+        test_grid.insert("x.265", true);
+        test_grid.insert("XviD", true);
+        test_grid.insert("DivX", true);
+        test_grid.insert("h264", false);
+        test_grid.insert("H.264", false);
+        test_grid.insert("AVC", false);
+        test_grid.insert("HEVC", false);
+        test_grid.insert("h.265", false);
+        test_grid.insert("VC-1", false);
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let encoder = super::parse(key.to_string()).1;
+
+            assert_eq!(val, encoder);
+        }
+    }
+
+    #[test]
+    fn test_parse_hdr() {
+        let mut test_grid: HashMap<&str, Vec<HdrFormat>> = HashMap::new();
+
+        test_grid.insert("Movie.2019.2160p.HDR.x265", vec![HdrFormat::HDR]);
+        test_grid.insert("Movie.2019.2160p.HDR10.x265", vec![HdrFormat::HDR10]);
+        test_grid.insert("Movie.2019.2160p.HDR10+.x265", vec![HdrFormat::HDR10Plus]);
+        test_grid.insert(
+            "Movie.2019.2160p.HDR10Plus.x265",
+            vec![HdrFormat::HDR10Plus],
+        );
+        test_grid.insert("Movie.2019.2160p.DoVi.x265", vec![HdrFormat::DolbyVision]);
+        test_grid.insert(
+            "Movie 2019 2160p Dolby Vision x265",
+            vec![HdrFormat::DolbyVision],
+        );
+        test_grid.insert("Show.S01E01.2160p.HLG.x265", vec![HdrFormat::HLG]);
+        test_grid.insert(
+            "Movie.2019.2160p.DV.HDR10.DD5.1.x265",
+            vec![HdrFormat::DolbyVision, HdrFormat::HDR10],
+        );
+        test_grid.insert("Movie.2019.1080p.HDRip.x264", vec![]);
+        test_grid.insert("Movie.2019.1080p.DVDRip.x264", vec![]);
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let formats = super::parse_hdr(key.to_string()).0;
+
+            assert_eq!(val, formats);
+        }
+
+        assert_eq!(
+            "Movie.2019.2160p.DD5.1.x265",
+            super::parse_hdr("Movie.2019.2160p.DV.HDR10.DD5.1.x265".to_string()).1
+        );
+    }
+
+    #[test]
+    fn test_parse_bit_depth() {
+        let mut test_grid: HashMap<&str, i32> = HashMap::new();
+
+        test_grid.insert("Movie.2019.2160p.10bit.x265", 10);
+        test_grid.insert("Movie.2019.2160p.10-bit.x265", 10);
+        test_grid.insert("[Group] Title - 01 [1080p Hi10P]", 10);
+        test_grid.insert("[Group] Title - 01 [Hi10]", 10);
+        test_grid.insert("Movie.2019.2160p.12bit.x265", 12);
+        test_grid.insert("Movie.2019.1080p.8bit.x264", 8);
+        test_grid.insert("Movie.2019.1080p.x264", 0);
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let bit_depth = super::parse_bit_depth(key.to_string()).0;
+
+            assert_eq!(val, bit_depth);
+        }
+    }
 }
@@ -0,0 +1,120 @@
+use regex::Regex;
+
+lazy_static! {
+    //Tags added by indexers re-posting scene releases: "x264-KILLERS [eztv]", "x264-FUM[ettv]"
+    static ref RE_INDEXER_TAG: Regex = Regex::new(
+        r"(?i)\s*\[(?P<tag>eztv|ettv|rartv|rarbg|tgx|etrg|ethd|publichd|glodls|1337x)\]"
+    )
+    .unwrap();
+    //Website prefixes: "www.Torrenting.com - Movie.2010", "[ www.Speed.cd ] - Movie.2010"
+    static ref RE_WEBSITE_PREFIX: Regex = Regex::new(
+        r"(?i)^[\[(]?\s*(?P<website>(?:www\.)?[a-z0-9-]+\.(?:com|net|org|to|ws|mx|tv|me|io|cc|cd|co|ag|is|info|xyz|biz|ru|in|lol|se|pw|club))\s*[\])]?\s*-\s*"
+    )
+    .unwrap();
+    //Website suffixes: "Movie.2010.720p-GRP [www.Torrenting.com]"
+    static ref RE_WEBSITE_SUFFIX: Regex = Regex::new(
+        r"(?i)[\s._-]*[\[(]\s*(?P<website>(?:www\.)?[a-z0-9-]+\.(?:com|net|org|to|ws|mx|tv|me|io|cc|cd|co|ag|is|info|xyz|biz|ru|in|lol|se|pw|club))\s*[\])]"
+    )
+    .unwrap();
+    //Unbracketed website suffixes: "x264-SPARKS.www.torrent.com". Without "www." the domain must
+    //follow a release group so that titles such as "Stand.By.Me" are left alone
+    static ref RE_WEBSITE_TRAILING: Regex = Regex::new(
+        r"(?i)(?:[\s._-]+(?P<www>www\.[a-z0-9-]+\.(?:com|net|org|to|ws|mx|tv|me|io|cc|cd|co|ag|is|info|xyz|biz|ru|in|lol|se|pw|club))|(?P<group>-[a-z0-9]+)[\s.](?P<domain>[a-z0-9-]+\.(?:com|net|org|to|ws|mx|tv|me|io|cc|cd|co|ag|is|info|xyz|biz|ru|in|lol|se|pw|club)))$"
+    )
+    .unwrap();
+}
+
+//Find the website and indexer tags added to a release name by the sites that re-posted it.
+//Returns the website, the indexer tag and the name without them
+pub fn parse(name: String) -> (Option<String>, Option<String>, String) {
+    let indexer_tag = RE_INDEXER_TAG
+        .captures(&name)
+        .map(|captures| captures["tag"].to_string());
+    let stripped = RE_INDEXER_TAG.replace_all(&name, "").to_string();
+
+    if let Some(captures) = RE_WEBSITE_PREFIX.captures(&stripped) {
+        let website = captures["website"].to_string();
+        let stripped = RE_WEBSITE_PREFIX.replace(&stripped, "").to_string();
+        return (Some(website), indexer_tag, stripped);
+    }
+
+    if let Some(captures) = RE_WEBSITE_SUFFIX.captures(&stripped) {
+        let website = captures["website"].to_string();
+        let stripped = RE_WEBSITE_SUFFIX.replace(&stripped, "").to_string();
+        return (Some(website), indexer_tag, stripped);
+    }
+
+    if let Some(captures) = RE_WEBSITE_TRAILING.captures(&stripped) {
+        let website = captures
+            .name("www")
+            .or_else(|| captures.name("domain"))
+            .unwrap()
+            .as_str()
+            .to_string();
+        let stripped = RE_WEBSITE_TRAILING.replace(&stripped, "${group}").to_string();
+        return (Some(website), indexer_tag, stripped);
+    }
+
+    (None, indexer_tag, stripped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parse_website() {
+        let mut test_grid: HashMap<&str, (Option<&str>, Option<&str>, &str)> = HashMap::new();
+        test_grid.insert(
+            "South Park S18E05 HDTV x264-KILLERS [eztv]",
+            (None, Some("eztv"), "South Park S18E05 HDTV x264-KILLERS"),
+        );
+        test_grid.insert(
+            "The Flash 2014 S01E04 HDTV x264-FUM[ettv]",
+            (None, Some("ettv"), "The Flash 2014 S01E04 HDTV x264-FUM"),
+        );
+        test_grid.insert(
+            "Rick.and.Morty.S03E10.720p.HDTV.x264-BATV[eztv].mkv",
+            (None, Some("eztv"), "Rick.and.Morty.S03E10.720p.HDTV.x264-BATV.mkv"),
+        );
+        test_grid.insert(
+            "www.Torrenting.com - Movie.2010.720p.BluRay.x264-GRP",
+            (Some("www.Torrenting.com"), None, "Movie.2010.720p.BluRay.x264-GRP"),
+        );
+        test_grid.insert(
+            "[ www.Speed.cd ] - Movie.2010.720p.BluRay.x264-GRP",
+            (Some("www.Speed.cd"), None, "Movie.2010.720p.BluRay.x264-GRP"),
+        );
+        test_grid.insert(
+            "Movie.2010.720p.BluRay.x264-GRP [www.Torrenting.com]",
+            (Some("www.Torrenting.com"), None, "Movie.2010.720p.BluRay.x264-GRP"),
+        );
+        test_grid.insert(
+            "Movie (2010) [1080p] [YTS.MX]",
+            (Some("YTS.MX"), None, "Movie (2010) [1080p]"),
+        );
+        test_grid.insert(
+            "Movie.2010.720p.BluRay.x264-SPARKS.www.torrent.com",
+            (Some("www.torrent.com"), None, "Movie.2010.720p.BluRay.x264-SPARKS"),
+        );
+        test_grid.insert(
+            "Movie.2010.720p.BluRay.x264-SPARKS.torrent.com",
+            (Some("torrent.com"), None, "Movie.2010.720p.BluRay.x264-SPARKS"),
+        );
+        test_grid.insert("Stand.By.Me", (None, None, "Stand.By.Me"));
+        test_grid.insert(
+            "[HorribleSubs] One Punch Man S2 - 03 [1080p].mkv",
+            (None, None, "[HorribleSubs] One Punch Man S2 - 03 [1080p].mkv"),
+        );
+
+        for (key, val) in test_grid {
+            println!("Test item: {}", key);
+            let (website, indexer_tag, stripped) = parse(key.to_string());
+
+            assert_eq!(val.0.map(|w| w.to_string()), website);
+            assert_eq!(val.1.map(|t| t.to_string()), indexer_tag);
+            assert_eq!(val.2, stripped);
+        }
+    }
+}
@@ -24,7 +24,7 @@ pub fn parse(name: &str) -> i32 {
     }
 
     unmarked_years
-        .get(0)
+        .first()
         .map_or(0, |y| y.parse::<i32>().unwrap_or(0))
 }
 